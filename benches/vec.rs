@@ -0,0 +1,36 @@
+// Compares the PartialEq based methods of VecU against the hash and ordered set based ones.
+// Run with `cargo bench`.
+extern crate underscore;
+
+use std::time::Instant;
+use underscore::vec::VecU;
+
+const SIZE: usize = 20000;
+
+fn sample(size: usize) -> Vec<usize> {
+    // Deterministic input with many duplicates in no particular order.
+    (0..size).map(|i| (i * 7919) % (size / 2)).collect()
+}
+
+fn bench<F: Fn() -> usize>(name: &str, f: F) {
+    let start = Instant::now();
+    let len = f();
+    println!("{:<24} {:>10.3?} (len {})", name, start.elapsed(), len);
+}
+
+fn main() {
+    let v = sample(SIZE);
+    let values = sample(SIZE / 4);
+
+    bench("uniq", || v.clone().uniq().len());
+    bench("uniq_hash", || v.clone().uniq_hash().len());
+    bench("uniq_ord", || v.clone().uniq_ord().len());
+
+    bench("without", || v.clone().without(&values).len());
+    bench("without_hash", || v.clone().without_hash(&values).len());
+    bench("without_ord", || v.clone().without_ord(&values).len());
+
+    bench("intersection", || v.clone().intersection(&values).len());
+    bench("intersection_hash", || v.clone().intersection_hash(&values).len());
+    bench("intersection_ord", || v.clone().intersection_ord(&values).len());
+}
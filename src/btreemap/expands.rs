@@ -1,9 +1,13 @@
 use std::collections::BTreeMap;
+use std::collections::btree_map;
+use error::{InvertError, CollisionError};
+use merge::Mergeable;
 use btreemap::BTreeMapU;
+use iter::expand::top_n_by;
 
-impl<K: Ord + Clone, V: Ord + Clone> BTreeMapU<K, V> for BTreeMap<K, V> {
+impl<K: Ord, V> BTreeMapU<K, V> for BTreeMap<K, V> {
 
-    fn invert(self) -> BTreeMap<V, K> {
+    fn invert(self) -> BTreeMap<V, K> where V: Ord {
         let mut invert = BTreeMap::new();
         for (key, value) in self.into_iter() {
             invert.insert(value, key);
@@ -11,12 +15,33 @@ impl<K: Ord + Clone, V: Ord + Clone> BTreeMapU<K, V> for BTreeMap<K, V> {
         return invert;
     }
 
+    fn invert_multi(self) -> BTreeMap<V, Vec<K>> where V: Ord {
+        let mut invert = BTreeMap::new();
+        for (key, value) in self.into_iter() {
+            invert.entry(value).or_insert_with(Vec::new).push(key);
+        }
+        return invert;
+    }
+
+    fn try_invert(self) -> Result<BTreeMap<V, K>, InvertError<K, V>> where V: Ord {
+        let multi = self.invert_multi();
+        let mut invert = BTreeMap::new();
+        let mut collisions = Vec::new();
+        for (value, mut keys) in multi.into_iter() {
+            if keys.len() > 1 { collisions.push((value, keys)); continue; }
+            if let Some(key) = keys.pop() { invert.insert(value, key); }
+        }
+
+        if ! collisions.is_empty() { return Err(InvertError { collisions }); }
+        return Ok(invert);
+    }
+
     fn pick(self, keys: &Vec<K>) -> BTreeMap<K, V> {
+        let mut origin = self;
         let mut picked = BTreeMap::new();
         for element in keys.iter() {
-            match self.get(element) {
-                Some(value) => { picked.insert(element.clone(), value.clone()); },
-                None => {},
+            if let Some((key, value)) = origin.remove_entry(element) {
+                picked.insert(key, value);
             }
         }
 
@@ -32,9 +57,9 @@ impl<K: Ord + Clone, V: Ord + Clone> BTreeMapU<K, V> for BTreeMap<K, V> {
     }
 
     fn omit(self, keys: &Vec<K>) -> BTreeMap<K, V> {
-        let mut omitted = BTreeMap::new();
-        for (key, value) in self.into_iter() {
-            if ! keys.contains(&key) { omitted.insert(key, value); }
+        let mut omitted = self;
+        for key in keys.iter() {
+            omitted.remove(key);
         }
         return omitted;
     }
@@ -48,14 +73,46 @@ impl<K: Ord + Clone, V: Ord + Clone> BTreeMapU<K, V> for BTreeMap<K, V> {
     }
 
     fn defaults(self, appends: BTreeMap<K, V>) -> BTreeMap<K, V> {
-        let mut origin = self.clone();
+        let mut origin = self;
         for (key, value) in appends.into_iter() {
-            if ! origin.contains_key(&key) { origin.insert(key, value); }
+            origin.entry(key).or_insert(value);
+        }
+
+        return origin;
+    }
+
+    fn assign(self, other: BTreeMap<K, V>) -> BTreeMap<K, V> {
+        let mut origin = self;
+        for (key, value) in other.into_iter() {
+            origin.insert(key, value);
         }
+        return origin;
+    }
 
+    fn defaults_many(self, fallbacks: Vec<BTreeMap<K, V>>) -> BTreeMap<K, V> {
+        let mut origin = self;
+        for appends in fallbacks.into_iter() {
+            origin = origin.defaults(appends);
+        }
         return origin;
     }
 
+    fn merge_with<F: Fn(&K, V, V) -> V>(self, other: BTreeMap<K, V>, f: F) -> BTreeMap<K, V> {
+        let mut origin = self;
+        for (key, value) in other.into_iter() {
+            let value = match origin.remove(&key) {
+                Some(existing) => f(&key, existing, value),
+                None => value,
+            };
+            origin.insert(key, value);
+        }
+        return origin;
+    }
+
+    fn deep_merge(self, other: BTreeMap<K, V>) -> BTreeMap<K, V> where V: Mergeable {
+        self.merge_with(other, |_, existing, value| existing.merge(value))
+    }
+
     fn pairs<'a>(&'a self) -> Vec<(&'a K, &'a V)> {
         let mut pairs = Vec::new();
         for (key, value) in self.iter() {
@@ -64,4 +121,82 @@ impl<K: Ord + Clone, V: Ord + Clone> BTreeMapU<K, V> for BTreeMap<K, V> {
 
         return pairs;
     }
+
+    fn max_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord {
+        self.iter().max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+    }
+
+    fn min_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord {
+        self.iter().min_by(|a, b| a.1.cmp(b.1))
+    }
+
+    fn max_entry_by<'a, B: Ord, F: Fn(&K, &V) -> B>(&'a self, f: F) -> Option<(&'a K, &'a V)> {
+        self.iter().map(|(key, value)| (f(key, value), key, value))
+            .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(a.1)))
+            .map(|(_, key, value)| (key, value))
+    }
+
+    fn sort_by_value(self) -> Vec<(K, V)> where V: Ord {
+        let mut sorted: Vec<(K, V)> = self.into_iter().collect();
+        sorted.sort_by(|a, b| a.1.cmp(&b.1));
+        return sorted;
+    }
+
+    fn top_n<B: Ord, F: Fn(&K, &V) -> B>(self, n: usize, f: F) -> Vec<(K, V)> {
+        top_n_by(self.into_iter(), n, |(key, value)| f(key, value))
+    }
+
+    fn map_values<W, F: Fn(&K, V) -> W>(self, f: F) -> BTreeMap<K, W> {
+        let mut mapped = BTreeMap::new();
+        for (key, value) in self.into_iter() {
+            let value = f(&key, value);
+            mapped.insert(key, value);
+        }
+        return mapped;
+    }
+
+    fn map_keys<J, F: Fn(&K, &V) -> J>(self, f: F) -> Result<BTreeMap<J, V>, CollisionError<J>> where J: Ord {
+        let mut mapped = BTreeMap::new();
+        for (key, value) in self.into_iter() {
+            match mapped.entry(f(&key, &value)) {
+                btree_map::Entry::Vacant(entry) => { entry.insert(value); },
+                btree_map::Entry::Occupied(entry) => return Err(CollisionError { key: entry.remove_entry().0 }),
+            }
+        }
+        return Ok(mapped);
+    }
+
+    fn map_keys_with<J, F: Fn(&K, &V) -> J, G: Fn(&J, V, V) -> V>(self, f: F, resolve: G) -> BTreeMap<J, V> where J: Ord {
+        let mut mapped = BTreeMap::new();
+        for (key, value) in self.into_iter() {
+            let key = f(&key, &value);
+            let value = match mapped.remove(&key) {
+                Some(existing) => resolve(&key, existing, value),
+                None => value,
+            };
+            mapped.insert(key, value);
+        }
+        return mapped;
+    }
+
+    fn filter_map_values<W, F: Fn(&K, V) -> Option<W>>(self, f: F) -> BTreeMap<K, W> {
+        let mut mapped = BTreeMap::new();
+        for (key, value) in self.into_iter() {
+            if let Some(value) = f(&key, value) { mapped.insert(key, value); }
+        }
+        return mapped;
+    }
+
+    fn find_key<'a, F: Fn(&K, &V) -> bool>(&'a self, f: F) -> Option<&'a K> {
+        self.iter().find(|&(key, value)| f(key, value)).map(|(key, _)| key)
+    }
+
+    fn partition<F: Fn(&K, &V) -> bool>(self, f: F) -> (BTreeMap<K, V>, BTreeMap<K, V>) {
+        let mut passed = BTreeMap::new();
+        let mut failed = BTreeMap::new();
+        for (key, value) in self.into_iter() {
+            if f(&key, &value) { passed.insert(key, value); } else { failed.insert(key, value); }
+        }
+        return (passed, failed);
+    }
 }
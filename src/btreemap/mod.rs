@@ -1,10 +1,13 @@
 use std::collections::BTreeMap;
+use error::{InvertError, CollisionError};
+use merge::Mergeable;
 
 pub mod expands;
 
 /// BTreeMapU expands BTreeMap
 pub trait BTreeMapU<K, V> {
     /// Returns a copy of the BTreeMap where the keys have become the values and the values the keys.
+    /// If several keys share the same value, the last key wins. See invert_multi and try_invert.
     /// # Example
     ///
     /// ```
@@ -18,7 +21,40 @@ pub trait BTreeMapU<K, V> {
     /// let inverted = sample.invert();
     /// // => BTreeMap { 1usize: 1isize, 2usize: 2isize }
     /// ```
-    fn invert(self) -> BTreeMap<V, K>;
+    fn invert(self) -> BTreeMap<V, K> where V: Ord;
+
+    /// Same as invert, but keeps every key when several keys share the same value.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 1usize);
+    /// sample.insert(3isize, 3usize);
+    ///
+    /// let inverted = sample.invert_multi();
+    /// // => BTreeMap { 1usize: vec!(1isize, 2isize), 3usize: vec!(3isize) }
+    /// ```
+    fn invert_multi(self) -> BTreeMap<V, Vec<K>> where V: Ord;
+
+    /// Same as invert, but returns InvertError listing the colliding keys when several keys share the same value.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 1usize);
+    ///
+    /// let error = sample.try_invert().unwrap_err();
+    /// assert_eq!(1usize, error.collisions[0].0);
+    /// ```
+    fn try_invert(self) -> Result<BTreeMap<V, K>, InvertError<K, V>> where V: Ord;
 
     /// Return a copy of the BTreeMap, filtered to only have values for the whitelisted keys.
     /// # Example
@@ -118,6 +154,91 @@ pub trait BTreeMapU<K, V> {
     /// ```
     fn defaults(self, appends: BTreeMap<K, V>) -> BTreeMap<K, V>;
 
+    /// Copy all of the entries of other into the BTreeMap, overwriting the existing ones.
+    /// This is _.extend of underscore.js.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut origin = BTreeMap::new();
+    /// origin.insert(1isize, 1usize);
+    /// origin.insert(2isize, 2usize);
+    ///
+    /// let mut other = BTreeMap::new();
+    /// other.insert(1isize, 10000usize);
+    /// other.insert(3isize, 3usize);
+    ///
+    /// let assigned = origin.assign(other);
+    /// // => BTreeMap { 1isize: 10000usize, 2isize: 2usize, 3isize: 3usize }
+    /// ```
+    fn assign(self, other: BTreeMap<K, V>) -> BTreeMap<K, V>;
+
+    /// Same as defaults, but takes several fallback maps. Earlier fallbacks win over later ones.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut origin = BTreeMap::new();
+    /// origin.insert(1isize, 1usize);
+    /// origin.insert(2isize, 2usize);
+    ///
+    /// let mut first = BTreeMap::new();
+    /// first.insert(3isize, 3usize);
+    /// let mut second = BTreeMap::new();
+    /// second.insert(3isize, 10000usize);
+    /// second.insert(4isize, 4usize);
+    ///
+    /// let defaults = origin.defaults_many(vec!(first, second));
+    /// // => BTreeMap { 1isize: 1usize, 2isize: 2usize, 3isize: 3usize, 4isize: 4usize }
+    /// ```
+    fn defaults_many(self, fallbacks: Vec<BTreeMap<K, V>>) -> BTreeMap<K, V>;
+
+    /// Copy all of the entries of other into the BTreeMap. When a key exists in both,
+    /// f is called with the key, the existing value and the value of other, and its result is kept.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut origin = BTreeMap::new();
+    /// origin.insert(1isize, 1usize);
+    /// origin.insert(2isize, 2usize);
+    ///
+    /// let mut other = BTreeMap::new();
+    /// other.insert(1isize, 10usize);
+    ///
+    /// let merged = origin.merge_with(other, |_, a, b| a + b);
+    /// // => BTreeMap { 1isize: 11usize, 2isize: 2usize }
+    /// ```
+    fn merge_with<F: Fn(&K, V, V) -> V>(self, other: BTreeMap<K, V>, f: F) -> BTreeMap<K, V>;
+
+    /// Merges other into the BTreeMap recursively. Nested maps are merged key by key, other values are overwritten.
+    /// See Mergeable.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut origin = BTreeMap::new();
+    /// origin.insert("db", BTreeMap::new());
+    /// origin.get_mut("db").unwrap().insert("host", "localhost");
+    /// origin.get_mut("db").unwrap().insert("port", "5432");
+    ///
+    /// let mut other = BTreeMap::new();
+    /// other.insert("db", BTreeMap::new());
+    /// other.get_mut("db").unwrap().insert("host", "db.example.com");
+    ///
+    /// let merged = origin.deep_merge(other);
+    /// assert_eq!("5432", merged["db"]["port"]);
+    /// ```
+    fn deep_merge(self, other: BTreeMap<K, V>) -> BTreeMap<K, V> where V: Mergeable;
+
     /// Convert a BTreeMap into a vector of (key, value) tuple pairs.
     /// # Example
     ///
@@ -133,4 +254,189 @@ pub trait BTreeMapU<K, V> {
     /// // => Vec<(1isize, 1usize), (2isize, 2usize)>
     /// ```
     fn pairs<'a>(&'a self) -> Vec<(&'a K, &'a V)>;
+
+    /// Returns the entry with the largest value, or None if the btreemap is empty.
+    /// If several entries tie, the one with the smallest key is returned.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut scores = BTreeMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    ///
+    /// assert_eq!(Some((&"larry", &50usize)), scores.max_by_value());
+    /// ```
+    fn max_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord;
+
+    /// Returns the entry with the smallest value, or None if the btreemap is empty.
+    /// If several entries tie, the one with the smallest key is returned.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut scores = BTreeMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    ///
+    /// assert_eq!(Some((&"moe", &40usize)), scores.min_by_value());
+    /// ```
+    fn min_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord;
+
+    /// Returns the entry for which f returns the largest result, or None if the btreemap is empty.
+    /// If several entries tie, the one with the smallest key is returned.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut ages = BTreeMap::new();
+    /// ages.insert("moe", 40isize);
+    /// ages.insert("larry", 50isize);
+    ///
+    /// assert_eq!(Some((&"larry", &50isize)), ages.max_entry_by(|name, _| name.len()));
+    /// ```
+    fn max_entry_by<'a, B: Ord, F: Fn(&K, &V) -> B>(&'a self, f: F) -> Option<(&'a K, &'a V)>;
+
+    /// Returns the entries sorted by value in ascending order. Entries with the same value stay in key order.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut scores = BTreeMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    /// scores.insert("curly", 10usize);
+    ///
+    /// assert_eq!(vec!(("curly", 10usize), ("moe", 40), ("larry", 50)), scores.sort_by_value());
+    /// ```
+    fn sort_by_value(self) -> Vec<(K, V)> where V: Ord;
+
+    /// Returns the n entries for which f returns the largest results, in descending order.
+    /// Entries with the same result stay in key order.
+    /// Only n entries are kept in a heap at a time, so the whole btreemap is never sorted.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut scores = BTreeMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    /// scores.insert("curly", 10usize);
+    ///
+    /// assert_eq!(vec!(("larry", 50usize), ("moe", 40)), scores.top_n(2, |_, &score| score));
+    /// ```
+    fn top_n<B: Ord, F: Fn(&K, &V) -> B>(self, n: usize, f: F) -> Vec<(K, V)>;
+
+    /// Returns a BTreeMap with the same keys, where each value is the result of f. Same as _.mapObject.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert("start", 5isize);
+    /// sample.insert("end", 12isize);
+    ///
+    /// let mapped = sample.map_values(|_, value| value + 5);
+    /// assert_eq!(17isize, mapped["end"]);
+    /// ```
+    fn map_values<W, F: Fn(&K, V) -> W>(self, f: F) -> BTreeMap<K, W>;
+
+    /// Returns a BTreeMap where each key is the result of f.
+    /// Returns CollisionError if several keys are mapped to the same key. Use map_keys_with to combine their values.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert("moe", 40usize);
+    /// sample.insert("larry", 50usize);
+    ///
+    /// let mapped = sample.clone().map_keys(|key, _| key.len()).unwrap();
+    /// assert_eq!(50usize, mapped[&5usize]);
+    /// assert!(sample.map_keys(|_, &age| age > 30).is_err());
+    /// ```
+    fn map_keys<J, F: Fn(&K, &V) -> J>(self, f: F) -> Result<BTreeMap<J, V>, CollisionError<J>> where J: Ord;
+
+    /// Same as map_keys, but the values of the keys mapped to the same key are combined by resolve.
+    /// resolve gets them in key order, so |_, first, _| first keeps the value of the smallest key.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert("Moe", 50usize);
+    /// sample.insert("moe", 40usize);
+    ///
+    /// let first = sample.clone().map_keys_with(|key, _| key.to_lowercase(), |_, first, _| first);
+    /// assert_eq!(50usize, first["moe"]);
+    /// let last = sample.map_keys_with(|key, _| key.to_lowercase(), |_, _, last| last);
+    /// assert_eq!(40usize, last["moe"]);
+    /// ```
+    fn map_keys_with<J, F: Fn(&K, &V) -> J, G: Fn(&J, V, V) -> V>(self, f: F, resolve: G) -> BTreeMap<J, V> where J: Ord;
+
+    /// Same as map_values, but the entries for which f returns None are removed.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert("one", "1");
+    /// sample.insert("two", "two");
+    ///
+    /// let parsed = sample.filter_map_values(|_, value| value.parse::<isize>().ok());
+    /// assert_eq!(1usize, parsed.len());
+    /// assert_eq!(1isize, parsed["one"]);
+    /// ```
+    fn filter_map_values<W, F: Fn(&K, V) -> Option<W>>(self, f: F) -> BTreeMap<K, W>;
+
+    /// Returns the smallest key for which the predicate passes, or None. Same as _.findKey.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert("moe", 40usize);
+    /// sample.insert("larry", 50usize);
+    ///
+    /// assert_eq!(Some(&"larry"), sample.find_key(|_, &age| age > 45));
+    /// assert_eq!(None, sample.find_key(|_, &age| age > 60));
+    /// ```
+    fn find_key<'a, F: Fn(&K, &V) -> bool>(&'a self, f: F) -> Option<&'a K>;
+
+    /// Splits the btreemap into the entries which pass the predicate and the ones which do not.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::btreemap::BTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 2usize);
+    ///
+    /// let (odd, even) = sample.partition(|_, &value| value % 2 == 1);
+    /// assert_eq!(1usize, odd[&1isize]);
+    /// assert_eq!(2usize, even[&2isize]);
+    /// ```
+    fn partition<F: Fn(&K, &V) -> bool>(self, f: F) -> (BTreeMap<K, V>, BTreeMap<K, V>);
 }
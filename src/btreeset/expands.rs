@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+use std::collections::BTreeMap;
+use btreeset::BTreeSetU;
+
+impl<T: Ord> BTreeSetU<T> for BTreeSet<T> {
+
+    fn intersection_set(self, other: &BTreeSet<T>) -> BTreeSet<T> {
+        let mut intersected = BTreeSet::new();
+        for element in self.into_iter() {
+            if other.contains(&element) { intersected.insert(element); }
+        }
+        return intersected;
+    }
+
+    fn difference_set(self, other: &BTreeSet<T>) -> BTreeSet<T> {
+        let mut difference = BTreeSet::new();
+        for element in self.into_iter() {
+            if ! other.contains(&element) { difference.insert(element); }
+        }
+        return difference;
+    }
+
+    fn union_set(self, other: &BTreeSet<T>) -> BTreeSet<T> where T: Clone {
+        let mut union = self;
+        for element in other.iter() {
+            if ! union.contains(element) { union.insert(element.clone()); }
+        }
+        return union;
+    }
+
+    fn symmetric_difference_set(self, other: &BTreeSet<T>) -> BTreeSet<T> where T: Clone {
+        let mut symmetric = BTreeSet::new();
+        for element in other.iter() {
+            if ! self.contains(element) { symmetric.insert(element.clone()); }
+        }
+        for element in self.into_iter() {
+            if ! other.contains(&element) { symmetric.insert(element); }
+        }
+        return symmetric;
+    }
+
+    fn partition<F: Fn(&T) -> bool>(self, f: F) -> (BTreeSet<T>, BTreeSet<T>) {
+        let mut passed = BTreeSet::new();
+        let mut failed = BTreeSet::new();
+        for element in self.into_iter() {
+            if f(&element) { passed.insert(element); } else { failed.insert(element); }
+        }
+        return (passed, failed);
+    }
+
+    fn pick_by_filter<F: Fn(&T) -> bool>(self, f: F) -> BTreeSet<T> {
+        let mut picked = BTreeSet::new();
+        for element in self.into_iter() {
+            if f(&element) { picked.insert(element); }
+        }
+        return picked;
+    }
+
+    fn omit_by_filter<F: Fn(&T) -> bool>(self, f: F) -> BTreeSet<T> {
+        let mut omitted = BTreeSet::new();
+        for element in self.into_iter() {
+            if ! f(&element) { omitted.insert(element); }
+        }
+        return omitted;
+    }
+
+    fn object<V, F: Fn(&T) -> V>(self, f: F) -> BTreeMap<T, V> {
+        let mut obj = BTreeMap::new();
+        for element in self.into_iter() {
+            let value = f(&element);
+            obj.insert(element, value);
+        }
+        return obj;
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::BTreeSet;
+use std::collections::BTreeMap;
+
+pub mod expands;
+
+/// BTreeSetU expands BTreeSet.
+/// intersection_set, difference_set, union_set and symmetric_difference_set consume the set and return a new one.
+/// They are suffixed so that the lazy methods of std, like BTreeSet::union, are not hidden.
+pub trait BTreeSetU<T> {
+    /// Returns the values of the BTreeSet which are also present in other.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let intersected = sample.intersection_set(&other);
+    /// // => BTreeSet { 2isize, 3isize }
+    /// ```
+    fn intersection_set(self, other: &BTreeSet<T>) -> BTreeSet<T>;
+
+    /// Returns the values of the BTreeSet which are not present in other.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let difference = sample.difference_set(&other);
+    /// // => BTreeSet { 1isize }
+    /// ```
+    fn difference_set(self, other: &BTreeSet<T>) -> BTreeSet<T>;
+
+    /// Returns the values present in either the BTreeSet or other.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let union = sample.union_set(&other);
+    /// // => BTreeSet { 1isize, 2isize, 3isize, 4isize }
+    /// ```
+    fn union_set(self, other: &BTreeSet<T>) -> BTreeSet<T> where T: Clone;
+
+    /// Returns the values present in the BTreeSet or other, but not in both.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let symmetric = sample.symmetric_difference_set(&other);
+    /// // => BTreeSet { 1isize, 4isize }
+    /// ```
+    fn symmetric_difference_set(self, other: &BTreeSet<T>) -> BTreeSet<T> where T: Clone;
+
+    /// Splits the BTreeSet into two sets: the values which pass the truth test f, and those which do not.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let (odd, even) = sample.partition(|&v| v % 2 == 1);
+    /// // => (BTreeSet { 1isize, 3isize }, BTreeSet { 2isize })
+    /// ```
+    fn partition<F: Fn(&T) -> bool>(self, f: F) -> (BTreeSet<T>, BTreeSet<T>);
+
+    /// Return a copy of the BTreeSet, filtered to only have values which pass the truth test f.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let picked = sample.pick_by_filter(|&v| v > 1);
+    /// // => BTreeSet { 2isize, 3isize }
+    /// ```
+    fn pick_by_filter<F: Fn(&T) -> bool>(self, f: F) -> BTreeSet<T>;
+
+    /// Return a copy of the BTreeSet, filtered to omit values which pass the truth test f.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let omitted = sample.omit_by_filter(|&v| v > 1);
+    /// // => BTreeSet { 1isize }
+    /// ```
+    fn omit_by_filter<F: Fn(&T) -> bool>(self, f: F) -> BTreeSet<T>;
+
+    /// Converts the BTreeSet into BTreeMap. Each value becomes a key and f computes the value for it.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use underscore::btreeset::BTreeSetU;
+    ///
+    /// let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let obj = sample.object(|&v| v * 10);
+    /// // => BTreeMap { 1isize: 10isize, 2isize: 20isize, 3isize: 30isize }
+    /// ```
+    fn object<V, F: Fn(&T) -> V>(self, f: F) -> BTreeMap<T, V>;
+}
@@ -0,0 +1,243 @@
+use std::hash::Hash;
+use std::hash::BuildHasher;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use vec::VecU;
+use iter::IteratorU;
+use hashmap::HashMapU;
+use btreemap::BTreeMapU;
+use error::ObjectError;
+
+/// Wraps a collection so that the methods of VecU, HashMapU and BTreeMapU can be chained.
+/// Vector operations are recorded lazily and run in a single pass when value() is called.
+/// # Example
+///
+/// ```
+/// use underscore::chain::chain;
+///
+/// let result = chain(vec!(1isize, 1, 2, 3, 10))
+///     .without(&vec!(2isize))
+///     .uniq()
+///     .reject(|&v| v > 5)
+///     .value();
+/// assert_eq!(vec!(1isize, 3), result);
+/// ```
+pub fn chain<'a, C: Chainable<'a>>(collection: C) -> C::Chain {
+    collection.chain()
+}
+
+/// Chainable is implemented by the collections which can be passed to chain().
+pub trait Chainable<'a> {
+    type Chain;
+
+    fn chain(self) -> Self::Chain;
+}
+
+impl<'a, T: 'a> Chainable<'a> for Vec<T> {
+    type Chain = Chain<'a, T>;
+
+    fn chain(self) -> Chain<'a, T> {
+        Chain { iter: Box::new(self.into_iter()) }
+    }
+}
+
+impl<'a, K, V, S> Chainable<'a> for HashMap<K, V, S> {
+    type Chain = HashMapChain<K, V, S>;
+
+    fn chain(self) -> HashMapChain<K, V, S> {
+        HashMapChain { map: self }
+    }
+}
+
+impl<'a, K, V> Chainable<'a> for BTreeMap<K, V> {
+    type Chain = BTreeMapChain<K, V>;
+
+    fn chain(self) -> BTreeMapChain<K, V> {
+        BTreeMapChain { map: self }
+    }
+}
+
+/// Chain is the vector stage of a chain. No vector is built until value() is called.
+pub struct Chain<'a, T> {
+    iter: Box<dyn Iterator<Item = T> + 'a>,
+}
+
+impl<'a, T: 'a> Chain<'a, T> {
+    /// Runs the recorded operations and returns the result.
+    pub fn value(self) -> Vec<T> {
+        self.iter.collect()
+    }
+
+    /// Same as VecU::first, but returns an owned value.
+    pub fn first(mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    /// Same as VecU::without.
+    pub fn without(self, values: &'a Vec<T>) -> Chain<'a, T> where T: PartialEq {
+        Chain { iter: Box::new(self.iter.without(values)) }
+    }
+
+    /// Same as VecU::intersection.
+    pub fn intersection(self, intersec: &'a Vec<T>) -> Chain<'a, T> where T: PartialEq {
+        Chain { iter: Box::new(self.iter.intersection(intersec)) }
+    }
+
+    /// Same as VecU::uniq.
+    pub fn uniq(self) -> Chain<'a, T> where T: PartialEq + Clone {
+        Chain { iter: Box::new(self.iter.uniq()) }
+    }
+
+    /// Same as VecU::reject.
+    pub fn reject<F: Fn(&T) -> bool + 'a>(self, f: F) -> Chain<'a, T> {
+        Chain { iter: Box::new(self.iter.reject(f)) }
+    }
+
+    /// Same as VecU::index_of.
+    pub fn index_of(mut self, value: &T) -> Option<usize> where T: PartialEq {
+        self.iter.position(|element| element.eq(value))
+    }
+
+    /// Same as VecU::last_index_of.
+    pub fn last_index_of(self, value: &T) -> Option<usize> where T: PartialEq {
+        let mut last = None;
+        for (index, element) in self.iter.enumerate() {
+            if element.eq(value) { last = Some(index); }
+        }
+        return last;
+    }
+
+    /// Same as VecU::object.
+    pub fn object<V>(self, values: Vec<V>) -> Result<BTreeMapChain<T, V>, ObjectError<T>> where T: Ord {
+        Ok(BTreeMapChain { map: self.value().object(values)? })
+    }
+
+    /// Same as VecU::group_by.
+    pub fn group_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMapChain<K, Vec<T>> {
+        BTreeMapChain { map: self.value().group_by(f) }
+    }
+
+    /// Same as VecU::group_by_hashmap.
+    pub fn group_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMapChain<K, Vec<T>> {
+        HashMapChain { map: self.value().group_by_hashmap(f) }
+    }
+
+    /// Same as VecU::count_by.
+    pub fn count_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMapChain<K, usize> {
+        BTreeMapChain { map: self.value().count_by(f) }
+    }
+
+    /// Same as VecU::count_by_hashmap.
+    pub fn count_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMapChain<K, usize> {
+        HashMapChain { map: self.value().count_by_hashmap(f) }
+    }
+
+    /// Same as VecU::index_by.
+    pub fn index_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMapChain<K, T> {
+        BTreeMapChain { map: self.value().index_by(f) }
+    }
+
+    /// Same as VecU::index_by_hashmap.
+    pub fn index_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMapChain<K, T> {
+        HashMapChain { map: self.value().index_by_hashmap(f) }
+    }
+}
+
+/// HashMapChain is the HashMap stage of a chain.
+pub struct HashMapChain<K, V, S = RandomState> {
+    map: HashMap<K, V, S>,
+}
+
+impl<K, V, S> HashMapChain<K, V, S> {
+    /// Returns the wrapped HashMap.
+    pub fn value(self) -> HashMap<K, V, S> {
+        self.map
+    }
+
+    /// Moves the chain back to the vector stage as (key, value) tuple pairs.
+    pub fn pairs<'a>(self) -> Chain<'a, (K, V)> where K: 'a, V: 'a {
+        Chain { iter: Box::new(self.map.into_iter()) }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> HashMapChain<K, V, S> {
+    /// Same as HashMapU::invert.
+    pub fn invert(self) -> HashMapChain<V, K, S> where V: Eq + Hash {
+        HashMapChain { map: self.map.invert() }
+    }
+
+    /// Same as HashMapU::pick.
+    pub fn pick(self, keys: Vec<K>) -> HashMapChain<K, V, S> {
+        HashMapChain { map: self.map.pick(keys) }
+    }
+
+    /// Same as HashMapU::pick_by_filter.
+    pub fn pick_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMapChain<K, V, S> {
+        HashMapChain { map: self.map.pick_by_filter(f) }
+    }
+
+    /// Same as HashMapU::omit.
+    pub fn omit(self, keys: Vec<K>) -> HashMapChain<K, V, S> {
+        HashMapChain { map: self.map.omit(keys) }
+    }
+
+    /// Same as HashMapU::omit_by_filter.
+    pub fn omit_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMapChain<K, V, S> {
+        HashMapChain { map: self.map.omit_by_filter(f) }
+    }
+
+    /// Same as HashMapU::defaults.
+    pub fn defaults(self, appends: HashMap<K, V, S>) -> HashMapChain<K, V, S> {
+        HashMapChain { map: self.map.defaults(appends) }
+    }
+}
+
+/// BTreeMapChain is the BTreeMap stage of a chain.
+pub struct BTreeMapChain<K, V> {
+    map: BTreeMap<K, V>,
+}
+
+impl<K, V> BTreeMapChain<K, V> {
+    /// Returns the wrapped BTreeMap.
+    pub fn value(self) -> BTreeMap<K, V> {
+        self.map
+    }
+
+    /// Same as BTreeMapU::pairs, but moves the chain back to the vector stage with owned pairs.
+    pub fn pairs<'a>(self) -> Chain<'a, (K, V)> where K: 'a, V: 'a {
+        Chain { iter: Box::new(self.map.into_iter()) }
+    }
+}
+
+impl<K: Ord, V> BTreeMapChain<K, V> {
+    /// Same as BTreeMapU::invert.
+    pub fn invert(self) -> BTreeMapChain<V, K> where V: Ord {
+        BTreeMapChain { map: self.map.invert() }
+    }
+
+    /// Same as BTreeMapU::pick.
+    pub fn pick(self, keys: &Vec<K>) -> BTreeMapChain<K, V> {
+        BTreeMapChain { map: self.map.pick(keys) }
+    }
+
+    /// Same as BTreeMapU::pick_by_filter.
+    pub fn pick_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> BTreeMapChain<K, V> {
+        BTreeMapChain { map: self.map.pick_by_filter(f) }
+    }
+
+    /// Same as BTreeMapU::omit.
+    pub fn omit(self, keys: &Vec<K>) -> BTreeMapChain<K, V> {
+        BTreeMapChain { map: self.map.omit(keys) }
+    }
+
+    /// Same as BTreeMapU::omit_by_filter.
+    pub fn omit_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> BTreeMapChain<K, V> {
+        BTreeMapChain { map: self.map.omit_by_filter(f) }
+    }
+
+    /// Same as BTreeMapU::defaults.
+    pub fn defaults(self, appends: BTreeMap<K, V>) -> BTreeMapChain<K, V> {
+        BTreeMapChain { map: self.map.defaults(appends) }
+    }
+}
@@ -0,0 +1,32 @@
+/// Compact is implemented by the values which can be dropped by compact of VecU.
+/// Option keeps the value inside Some, and Result keeps the value inside Ok.
+/// # Example
+///
+/// ```
+/// use underscore::compact::Compact;
+///
+/// assert_eq!(Some(1isize), Some(1isize).into_option());
+/// assert_eq!(None, Err::<isize, &str>("error").into_option());
+/// ```
+pub trait Compact {
+    type Value;
+
+    /// Returns the value to keep, or None to drop it.
+    fn into_option(self) -> Option<Self::Value>;
+}
+
+impl<T> Compact for Option<T> {
+    type Value = T;
+
+    fn into_option(self) -> Option<T> {
+        self
+    }
+}
+
+impl<T, E> Compact for Result<T, E> {
+    type Value = T;
+
+    fn into_option(self) -> Option<T> {
+        self.ok()
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::error::Error;
+
+/// InvertError is returned by try_invert of HashMapU and BTreeMapU when some keys share the same value,
+/// which means the map can not be inverted without losing keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvertError<K, V> {
+    /// Each value shared by more than one key, with all the keys which have it.
+    pub collisions: Vec<(V, Vec<K>)>,
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Display for InvertError<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "map is not invertible, keys collide on values:")?;
+        for (value, keys) in self.collisions.iter() {
+            write!(f, " {:?} => {:?}", value, keys)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> Error for InvertError<K, V> {}
+
+/// CollisionError is returned by map_keys of HashMapU and BTreeMapU when several keys are mapped to the same key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollisionError<K> {
+    /// The key which several keys are mapped to.
+    pub key: K,
+}
+
+impl<K: fmt::Debug> fmt::Display for CollisionError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "several keys are mapped to {:?}", self.key)
+    }
+}
+
+impl<K: fmt::Debug> Error for CollisionError<K> {}
+
+/// ObjectError is returned by object of VecU when the keys and the values can not be zipped into a map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjectError<K> {
+    /// The keys and the values have different lengths. Returned with LengthPolicy::Error.
+    LengthMismatch { keys: usize, values: usize },
+    /// The key appears more than once. Returned with DuplicatePolicy::Error.
+    DuplicateKey(K),
+}
+
+impl<K: fmt::Debug> fmt::Display for ObjectError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ObjectError::LengthMismatch { keys, values } => write!(f, "{} keys do not match {} values", keys, values),
+            ObjectError::DuplicateKey(ref key) => write!(f, "duplicate key {:?}", key),
+        }
+    }
+}
+
+impl<K: fmt::Debug> Error for ObjectError<K> {}
+
+/// TemplateError is returned by Template::compile when the template can not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateError {
+    pub kind: TemplateErrorKind,
+    /// The line of the tag which caused the error, starting from 1.
+    pub line: usize,
+    /// The column of the tag which caused the error in chars, starting from 1.
+    pub column: usize,
+}
+
+/// TemplateErrorKind is the reason of TemplateError.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateErrorKind {
+    /// The tag has no closing delimiter.
+    UnclosedTag,
+    /// The if or each block has no end tag. Holds the tag.
+    UnclosedBlock(String),
+    /// The else or end tag has no block to belong to. Holds the tag.
+    UnexpectedTag(String),
+    /// The tag is not an expression, if, else, each or end. Holds the tag.
+    InvalidTag(String),
+    /// The opening or closing delimiter is empty.
+    EmptyDelimiter,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            TemplateErrorKind::UnclosedTag => write!(f, "tag is not closed"),
+            TemplateErrorKind::UnclosedBlock(ref tag) => write!(f, "{:?} has no end", tag),
+            TemplateErrorKind::UnexpectedTag(ref tag) => write!(f, "unexpected {:?}", tag),
+            TemplateErrorKind::InvalidTag(ref tag) => write!(f, "invalid tag {:?}", tag),
+            TemplateErrorKind::EmptyDelimiter => write!(f, "delimiter is empty"),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// PathError is returned by Value::set_path when the path can not be set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathError {
+    /// The path could not be parsed. Holds the byte position of the error.
+    Syntax(usize),
+    /// A key was used on a value which is not an object, or an index on a value which is not an array.
+    /// Holds the part of the path which points to that value.
+    NotContainer(String),
+    /// The index is past the end of the array. Setting the index right after the last element appends to it.
+    OutOfBounds(String),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PathError::Syntax(position) => write!(f, "invalid path at {}", position),
+            PathError::NotContainer(ref path) => write!(f, "value at {:?} is not an object or array", path),
+            PathError::OutOfBounds(ref path) => write!(f, "index out of bounds at {:?}", path),
+        }
+    }
+}
+
+impl Error for PathError {}
@@ -0,0 +1,53 @@
+/// Flatten is implemented by the values which can be deep flattened by flatten of VecU.
+/// Vec is flattened recursively, and other values are leaves.
+/// Implement it with Leaf = Self to flatten your own type.
+/// # Example
+///
+/// ```
+/// use underscore::flatten::Flatten;
+///
+/// let mut flattened = Vec::new();
+/// vec!(vec!(1isize), vec!(2, 3)).flatten_into(&mut flattened);
+/// assert_eq!(vec!(1isize, 2, 3), flattened);
+/// ```
+pub trait Flatten {
+    type Leaf;
+
+    /// Pushes the leaves of self into flattened.
+    fn flatten_into(self, flattened: &mut Vec<Self::Leaf>);
+}
+
+impl<T: Flatten> Flatten for Vec<T> {
+    type Leaf = T::Leaf;
+
+    fn flatten_into(self, flattened: &mut Vec<T::Leaf>) {
+        for element in self.into_iter() {
+            element.flatten_into(flattened);
+        }
+    }
+}
+
+impl<'a> Flatten for &'a str {
+    type Leaf = &'a str;
+
+    fn flatten_into(self, flattened: &mut Vec<&'a str>) {
+        flattened.push(self);
+    }
+}
+
+macro_rules! flatten_as_leaf {
+    ($($t:ty),*) => {
+        $(
+            impl Flatten for $t {
+                type Leaf = $t;
+
+                fn flatten_into(self, flattened: &mut Vec<$t>) {
+                    flattened.push(self);
+                }
+            }
+        )*
+    }
+}
+
+flatten_as_leaf!(bool, char, String, (),
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
@@ -0,0 +1,74 @@
+use std::rc::Rc;
+use std::cell::Cell;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Clock is the source of time for throttle and debounce.
+pub trait Clock {
+    /// Returns the time elapsed since the origin of the clock.
+    fn now(&self) -> Duration;
+}
+
+/// SystemClock reads the monotonic system clock.
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock { origin: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// ManualClock only moves when it is advanced by hand. Clones share the same time,
+/// so tests can keep one clone and pass another to throttle or debounce.
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use underscore::function::clock::{Clock, ManualClock};
+///
+/// let clock = ManualClock::new();
+/// let shared = clock.clone();
+/// clock.advance(Duration::from_millis(10));
+/// assert_eq!(Duration::from_millis(10), shared.now());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    /// Moves the clock forward by duration.
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+
+    /// Sets the clock to now.
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
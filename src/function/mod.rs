@@ -0,0 +1,400 @@
+use std::mem;
+use std::hash::Hash;
+use std::hash::BuildHasher;
+use std::time::Duration;
+use std::collections::HashMap;
+use std::collections::BTreeMap;
+use self::clock::{Clock, SystemClock};
+
+pub mod clock;
+
+/// Cache stores the results of memoized functions.
+/// It is implemented for HashMap and BTreeMap.
+pub trait Cache<K, V> {
+    /// Returns the cached value for key.
+    fn lookup(&self, key: &K) -> Option<&V>;
+
+    /// Stores value for key.
+    fn store(&mut self, key: K, value: V);
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Cache<K, V> for HashMap<K, V, S> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+impl<K: Ord, V> Cache<K, V> for BTreeMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+// The state of once. Poisoned is only seen again when the first call panicked.
+enum Once<F, R> {
+    Pending(F),
+    Done(R),
+    Poisoned,
+}
+
+/// Creates a version of the function that can only be called one time.
+/// Repeated calls return the value of the first call.
+/// # Panics
+/// If the first call panicked, the later calls panic as well, like std::sync::Once.
+/// # Example
+///
+/// ```
+/// use underscore::function::once;
+///
+/// let mut initialize = once(|| vec!(1isize, 2));
+/// assert_eq!(vec!(1isize, 2), initialize());
+/// assert_eq!(vec!(1isize, 2), initialize());
+/// ```
+pub fn once<R: Clone, F: FnOnce() -> R>(f: F) -> impl FnMut() -> R {
+    let mut state = Once::Pending(f);
+    move || {
+        let result = match mem::replace(&mut state, Once::Poisoned) {
+            Once::Pending(f) => f(),
+            Once::Done(result) => result,
+            Once::Poisoned => panic!("once: the first call panicked"),
+        };
+        state = Once::Done(result.clone());
+        return result;
+    }
+}
+
+/// Creates a version of the function that will only be run after being called n times.
+/// Calls before that return None.
+/// # Example
+///
+/// ```
+/// use underscore::function::after;
+///
+/// let mut render = after(2, || "rendered");
+/// assert_eq!(None, render());
+/// assert_eq!(Some("rendered"), render());
+/// assert_eq!(Some("rendered"), render());
+/// ```
+pub fn after<R, F: FnMut() -> R>(n: usize, mut f: F) -> impl FnMut() -> Option<R> {
+    let mut count = 0usize;
+    move || {
+        count = count.saturating_add(1);
+        if count < n { return None; }
+        return Some(f());
+    }
+}
+
+/// Creates a version of the function that can be called no more than n - 1 times.
+/// Later calls return the result of the last call, or None if the function was never called.
+/// # Example
+///
+/// ```
+/// use underscore::function::before;
+///
+/// let mut count = 0isize;
+/// let mut increment = before(3, || { count += 1; count });
+/// assert_eq!(Some(1isize), increment());
+/// assert_eq!(Some(2isize), increment());
+/// assert_eq!(Some(2isize), increment());
+/// ```
+pub fn before<R: Clone, F: FnMut() -> R>(n: usize, mut f: F) -> impl FnMut() -> Option<R> {
+    let mut count = 0usize;
+    let mut result = None;
+    move || {
+        count = count.saturating_add(1);
+        if count < n { result = Some(f()); }
+        return result.clone();
+    }
+}
+
+/// Returns a new negated version of the predicate function.
+/// It can be passed to VecU::reject and the other methods which take a predicate.
+/// # Example
+///
+/// ```
+/// use underscore::vec::VecU;
+/// use underscore::function::negate;
+///
+/// let is_small = |v: &isize| *v < 10;
+/// assert_eq!(vec!(1isize, 2), vec!(1isize, 2, 10).reject(negate(is_small)));
+/// ```
+pub fn negate<T: ?Sized, F: Fn(&T) -> bool>(f: F) -> impl Fn(&T) -> bool {
+    move |value| ! f(value)
+}
+
+/// Same as negate, but for predicates which take a key and a value.
+/// It can be passed to pick_by_filter and omit_by_filter of HashMapU and BTreeMapU.
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use underscore::btreemap::BTreeMapU;
+/// use underscore::function::negate2;
+///
+/// let mut sample = BTreeMap::new();
+/// sample.insert(1isize, 1usize);
+/// sample.insert(2isize, 2usize);
+///
+/// let picked = sample.pick_by_filter(negate2(|k: &isize, _: &usize| *k == 1));
+/// // => BTreeMap { 2isize: 2usize }
+/// ```
+pub fn negate2<A: ?Sized, B: ?Sized, F: Fn(&A, &B) -> bool>(f: F) -> impl Fn(&A, &B) -> bool {
+    move |a, b| ! f(a, b)
+}
+
+/// Returns the composition of f and g. compose(f, g)(x) is f(g(x)).
+/// # Example
+///
+/// ```
+/// use underscore::function::compose;
+///
+/// let greet = |name: String| format!("hi: {}", name);
+/// let exclaim = |statement: &str| format!("{}!", statement.to_uppercase());
+/// let welcome = compose(greet, exclaim);
+/// assert_eq!("hi: MOE!", welcome("moe"));
+/// ```
+pub fn compose<A, B, C, F: Fn(B) -> C, G: Fn(A) -> B>(f: F, g: G) -> impl Fn(A) -> C {
+    move |a| f(g(a))
+}
+
+/// Partially applies a function of two arguments by filling in its first argument.
+/// # Example
+///
+/// ```
+/// use underscore::function::partial2;
+///
+/// let sub_from_20 = partial2(|a: isize, b: isize| a - b, 20);
+/// assert_eq!(15isize, sub_from_20(5));
+/// ```
+pub fn partial2<A: Clone, B, R, F: Fn(A, B) -> R>(f: F, a: A) -> impl Fn(B) -> R {
+    move |b| f(a.clone(), b)
+}
+
+/// Partially applies a function of three arguments by filling in its first argument.
+/// # Example
+///
+/// ```
+/// use underscore::function::partial3;
+///
+/// let sum_from_100 = partial3(|a: isize, b: isize, c: isize| a + b + c, 100);
+/// assert_eq!(103isize, sum_from_100(1, 2));
+/// ```
+pub fn partial3<A: Clone, B, C, R, F: Fn(A, B, C) -> R>(f: F, a: A) -> impl Fn(B, C) -> R {
+    move |b, c| f(a.clone(), b, c)
+}
+
+/// Partially applies a function of four arguments by filling in its first argument.
+/// # Example
+///
+/// ```
+/// use underscore::function::partial4;
+///
+/// let sum_from_100 = partial4(|a: isize, b: isize, c: isize, d: isize| a + b + c + d, 100);
+/// assert_eq!(106isize, sum_from_100(1, 2, 3));
+/// ```
+pub fn partial4<A: Clone, B, C, D, R, F: Fn(A, B, C, D) -> R>(f: F, a: A) -> impl Fn(B, C, D) -> R {
+    move |b, c, d| f(a.clone(), b, c, d)
+}
+
+/// Memoizes a function by caching the computed result in HashMap keyed by the argument.
+/// # Example
+///
+/// ```
+/// use underscore::function::memoize;
+///
+/// let mut square = memoize(|v: usize| v * v);
+/// assert_eq!(16usize, square(4));
+/// assert_eq!(16usize, square(4));
+/// ```
+pub fn memoize<A: Eq + Hash + Clone, R: Clone, F: Fn(A) -> R>(f: F) -> impl FnMut(A) -> R {
+    memoize_with(f, |a: &A| a.clone(), HashMap::new())
+}
+
+/// Memoizes a function. hasher computes the cache key from the argument,
+/// and cache stores the results. HashMap and BTreeMap can be used as cache.
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use underscore::function::memoize_with;
+///
+/// let mut length = memoize_with(|s: &str| s.chars().count(), |s: &&str| s.to_string(), BTreeMap::new());
+/// assert_eq!(5usize, length("hello"));
+/// ```
+pub fn memoize_with<A, K, R, F, H, C>(f: F, hasher: H, mut cache: C) -> impl FnMut(A) -> R
+    where R: Clone, F: Fn(A) -> R, H: Fn(&A) -> K, C: Cache<K, R> {
+    move |a| {
+        let key = hasher(&a);
+        if let Some(result) = cache.lookup(&key) { return result.clone(); }
+        let result = f(a);
+        cache.store(key, result.clone());
+        return result;
+    }
+}
+
+/// Creates a throttled version of the function, that calls f at most once per every wait.
+/// See Throttle for details.
+pub fn throttle<A, R, F: FnMut(A) -> R>(f: F, wait: Duration) -> Throttle<A, F, SystemClock> {
+    throttle_with_clock(f, wait, SystemClock::new())
+}
+
+/// Same as throttle, but reads time from clock.
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use underscore::function::throttle_with_clock;
+/// use underscore::function::clock::ManualClock;
+///
+/// let clock = ManualClock::new();
+/// let mut flush = throttle_with_clock(|v: isize| v, Duration::from_millis(100), clock.clone());
+/// assert_eq!(Some(1isize), flush.call(1));
+/// assert_eq!(None, flush.call(2));
+///
+/// clock.advance(Duration::from_millis(100));
+/// assert_eq!(Some(2isize), flush.poll());
+/// ```
+pub fn throttle_with_clock<A, R, F: FnMut(A) -> R, C: Clock>(f: F, wait: Duration, clock: C) -> Throttle<A, F, C> {
+    Throttle { f, wait, clock, previous: None, pending: None }
+}
+
+/// Throttle calls the wrapped function at most once per every wait.
+/// The first call runs immediately. Calls during the wait are not run, but the argument
+/// of the latest one is kept and run by poll() when the wait is over (trailing call).
+/// There are no timers, so poll() should be called periodically.
+pub struct Throttle<A, F, C> {
+    f: F,
+    wait: Duration,
+    clock: C,
+    previous: Option<Duration>,
+    pending: Option<A>,
+}
+
+impl<A, R, F: FnMut(A) -> R, C: Clock> Throttle<A, F, C> {
+    /// Calls the function if wait has passed since the last run. Otherwise returns None
+    /// and keeps arg for the trailing call.
+    pub fn call(&mut self, arg: A) -> Option<R> {
+        let now = self.clock.now();
+        if ! self.is_ready(now) {
+            self.pending = Some(arg);
+            return None;
+        }
+        self.pending = None;
+        self.previous = Some(now);
+        return Some((self.f)(arg));
+    }
+
+    /// Runs the trailing call if there is a pending argument and wait has passed.
+    pub fn poll(&mut self) -> Option<R> {
+        let now = self.clock.now();
+        if self.pending.is_none() || ! self.is_ready(now) { return None; }
+        self.previous = Some(now);
+        return self.pending.take().map(|arg| (self.f)(arg));
+    }
+
+    /// Drops the pending trailing call.
+    pub fn cancel(&mut self) {
+        self.pending = None;
+    }
+
+    fn is_ready(&self, now: Duration) -> bool {
+        match self.previous {
+            Some(previous) => now.checked_sub(previous).is_some_and(|elapsed| elapsed >= self.wait),
+            None => true,
+        }
+    }
+}
+
+/// Edge decides when a debounced function runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    /// Run on the first call of a burst.
+    Leading,
+    /// Run by poll() once the calls have stopped for wait.
+    Trailing,
+    /// Run on both edges. The trailing call only runs if there were more calls during the burst.
+    Both,
+}
+
+/// Creates a debounced version of the function. See Debounce for details.
+pub fn debounce<A, R, F: FnMut(A) -> R>(f: F, wait: Duration, edge: Edge) -> Debounce<A, F, SystemClock> {
+    debounce_with_clock(f, wait, edge, SystemClock::new())
+}
+
+/// Same as debounce, but reads time from clock.
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use underscore::function::{debounce_with_clock, Edge};
+/// use underscore::function::clock::ManualClock;
+///
+/// let clock = ManualClock::new();
+/// let mut refresh = debounce_with_clock(|v: isize| v, Duration::from_millis(100), Edge::Trailing, clock.clone());
+/// assert_eq!(None, refresh.call(1));
+/// assert_eq!(None, refresh.call(2));
+/// assert_eq!(None, refresh.poll());
+///
+/// clock.advance(Duration::from_millis(100));
+/// assert_eq!(Some(2isize), refresh.poll());
+/// ```
+pub fn debounce_with_clock<A, R, F: FnMut(A) -> R, C: Clock>(f: F, wait: Duration, edge: Edge, clock: C) -> Debounce<A, F, C> {
+    Debounce { f, wait, edge, clock, last_call: None, pending: None }
+}
+
+/// Debounce postpones the wrapped function until wait has elapsed since the last call.
+/// With Edge::Leading it runs on the first call of a burst instead.
+/// There are no timers, so poll() should be called periodically to run trailing calls.
+pub struct Debounce<A, F, C> {
+    f: F,
+    wait: Duration,
+    edge: Edge,
+    clock: C,
+    last_call: Option<Duration>,
+    pending: Option<A>,
+}
+
+impl<A, R, F: FnMut(A) -> R, C: Clock> Debounce<A, F, C> {
+    /// Records a call. Returns the result if the function ran on the leading edge.
+    /// If the trailing call of the previous burst is overdue because poll() was not called,
+    /// it runs first. Its result is returned with Edge::Trailing, and dropped with Edge::Both.
+    pub fn call(&mut self, arg: A) -> Option<R> {
+        let now = self.clock.now();
+        let is_idle = self.is_idle(now);
+        self.last_call = Some(now);
+
+        let overdue = if is_idle { self.pending.take().map(|pending| (self.f)(pending)) } else { None };
+        if is_idle && self.edge != Edge::Trailing {
+            return Some((self.f)(arg));
+        }
+        if self.edge != Edge::Leading { self.pending = Some(arg); }
+        return overdue;
+    }
+
+    /// Runs the trailing call if there is a pending argument and no call came in for wait.
+    pub fn poll(&mut self) -> Option<R> {
+        let now = self.clock.now();
+        if self.pending.is_none() || ! self.is_idle(now) { return None; }
+        return self.pending.take().map(|arg| (self.f)(arg));
+    }
+
+    /// Drops the pending trailing call.
+    pub fn cancel(&mut self) {
+        self.pending = None;
+    }
+
+    fn is_idle(&self, now: Duration) -> bool {
+        match self.last_call {
+            Some(last_call) => now.checked_sub(last_call).is_some_and(|elapsed| elapsed >= self.wait),
+            None => true,
+        }
+    }
+}
@@ -1,62 +1,194 @@
 use std::hash::Hash;
+use std::hash::BuildHasher;
 use std::collections::HashMap;
+use std::collections::hash_map;
+use error::{InvertError, CollisionError};
+use merge::Mergeable;
 use hashmap::HashMapU;
+use iter::expand::top_n_by;
 
-impl<'a, K: Eq + Hash + Clone, V: Eq + Hash + Clone> HashMapU<K, V> for HashMap<K, V> {
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> HashMapU<K, V, S> for HashMap<K, V, S> {
 
-    fn invert(self) -> HashMap<V, K> {
-        let mut invert = HashMap::new();
+    fn invert(self) -> HashMap<V, K, S> where V: Eq + Hash {
+        let mut invert = HashMap::with_hasher(self.hasher().clone());
         for (key, value) in self.into_iter() {
             invert.insert(value, key);
         }
         return invert;
     }
 
-    fn pick(self, keys: Vec<K>) -> HashMap<K, V> {
-        let mut picked = HashMap::new();
+    fn invert_multi(self) -> HashMap<V, Vec<K>, S> where V: Eq + Hash {
+        let mut invert = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            invert.entry(value).or_insert_with(Vec::new).push(key);
+        }
+        return invert;
+    }
+
+    fn try_invert(self) -> Result<HashMap<V, K, S>, InvertError<K, V>> where V: Eq + Hash {
+        let multi = self.invert_multi();
+        let mut invert = HashMap::with_hasher(multi.hasher().clone());
+        let mut collisions = Vec::new();
+        for (value, mut keys) in multi.into_iter() {
+            if keys.len() > 1 { collisions.push((value, keys)); continue; }
+            if let Some(key) = keys.pop() { invert.insert(value, key); }
+        }
+
+        if ! collisions.is_empty() { return Err(InvertError { collisions }); }
+        return Ok(invert);
+    }
+
+    fn pick(self, keys: Vec<K>) -> HashMap<K, V, S> {
+        let mut origin = self;
+        let mut picked = HashMap::with_hasher(origin.hasher().clone());
         for element in keys.into_iter() {
-            if self.contains_key(&element) {
-                let v = self[&element].clone();
-                picked.insert(element, v);
+            if let Some(value) = origin.remove(&element) {
+                picked.insert(element, value);
             }
         }
         return picked;
     }
 
-    fn pick_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V> {
-        let mut picked = HashMap::new();
-        for key in self.keys() {
-            let cp_key = key.clone();
-            let cp_val = self[&cp_key].clone();
-            if f(&cp_key, &cp_val) { picked.insert(cp_key, cp_val); }
+    fn pick_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V, S> {
+        let mut picked = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            if f(&key, &value) { picked.insert(key, value); }
         }
         return picked;
     }
 
-    fn omit(self, keys: Vec<K>) -> HashMap<K, V> {
-        let mut omitted = HashMap::new();
-        for (key, value) in self.into_iter() {
-            if ! keys.contains(&key) { omitted.insert(key, value); }
+    fn omit(self, keys: Vec<K>) -> HashMap<K, V, S> {
+        let mut omitted = self;
+        for key in keys.iter() {
+            omitted.remove(key);
         }
 
         return omitted;
     }
 
-    fn omit_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V> {
-        let mut omitted = HashMap::new();
-        for key in self.keys() {
-            let cp_key = key.clone();
-            let cp_val = self[&cp_key].clone();
-            if ! f(&cp_key, &cp_val) { omitted.insert(cp_key, cp_val); }
+    fn omit_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V, S> {
+        let mut omitted = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            if ! f(&key, &value) { omitted.insert(key, value); }
         }
         return omitted;
     }
 
-    fn defaults(self, appends: HashMap<K, V>) -> HashMap<K, V> {
-        let mut origin = self.clone();
+    fn defaults(self, appends: HashMap<K, V, S>) -> HashMap<K, V, S> {
+        let mut origin = self;
         for (key, value) in appends.into_iter() {
-            if ! origin.contains_key(&key) { origin.insert(key, value); }
+            origin.entry(key).or_insert(value);
+        }
+        return origin;
+    }
+
+    fn assign(self, other: HashMap<K, V, S>) -> HashMap<K, V, S> {
+        let mut origin = self;
+        for (key, value) in other.into_iter() {
+            origin.insert(key, value);
+        }
+        return origin;
+    }
+
+    fn defaults_many(self, fallbacks: Vec<HashMap<K, V, S>>) -> HashMap<K, V, S> {
+        let mut origin = self;
+        for appends in fallbacks.into_iter() {
+            origin = origin.defaults(appends);
         }
         return origin;
     }
+
+    fn merge_with<F: Fn(&K, V, V) -> V>(self, other: HashMap<K, V, S>, f: F) -> HashMap<K, V, S> {
+        let mut origin = self;
+        for (key, value) in other.into_iter() {
+            let value = match origin.remove(&key) {
+                Some(existing) => f(&key, existing, value),
+                None => value,
+            };
+            origin.insert(key, value);
+        }
+        return origin;
+    }
+
+    fn deep_merge(self, other: HashMap<K, V, S>) -> HashMap<K, V, S> where V: Mergeable {
+        self.merge_with(other, |_, existing, value| existing.merge(value))
+    }
+
+    fn max_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord {
+        self.iter().max_by(|a, b| a.1.cmp(b.1))
+    }
+
+    fn min_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord {
+        self.iter().min_by(|a, b| a.1.cmp(b.1))
+    }
+
+    fn max_entry_by<'a, B: Ord, F: Fn(&K, &V) -> B>(&'a self, f: F) -> Option<(&'a K, &'a V)> {
+        self.iter().map(|(key, value)| (f(key, value), key, value))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, key, value)| (key, value))
+    }
+
+    fn sort_by_value(self) -> Vec<(K, V)> where V: Ord {
+        let mut sorted: Vec<(K, V)> = self.into_iter().collect();
+        sorted.sort_by(|a, b| a.1.cmp(&b.1));
+        return sorted;
+    }
+
+    fn top_n<B: Ord, F: Fn(&K, &V) -> B>(self, n: usize, f: F) -> Vec<(K, V)> {
+        top_n_by(self.into_iter(), n, |(key, value)| f(key, value))
+    }
+
+    fn map_values<W, F: Fn(&K, V) -> W>(self, f: F) -> HashMap<K, W, S> {
+        let mut mapped = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            let value = f(&key, value);
+            mapped.insert(key, value);
+        }
+        return mapped;
+    }
+
+    fn map_keys<J, F: Fn(&K, &V) -> J>(self, f: F) -> Result<HashMap<J, V, S>, CollisionError<J>> where J: Eq + Hash {
+        let mut mapped = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            match mapped.entry(f(&key, &value)) {
+                hash_map::Entry::Vacant(entry) => { entry.insert(value); },
+                hash_map::Entry::Occupied(entry) => return Err(CollisionError { key: entry.remove_entry().0 }),
+            }
+        }
+        return Ok(mapped);
+    }
+
+    fn map_keys_with<J, F: Fn(&K, &V) -> J, G: Fn(&J, V, V) -> V>(self, f: F, resolve: G) -> HashMap<J, V, S> where J: Eq + Hash {
+        let mut mapped = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            let key = f(&key, &value);
+            let value = match mapped.remove(&key) {
+                Some(existing) => resolve(&key, existing, value),
+                None => value,
+            };
+            mapped.insert(key, value);
+        }
+        return mapped;
+    }
+
+    fn filter_map_values<W, F: Fn(&K, V) -> Option<W>>(self, f: F) -> HashMap<K, W, S> {
+        let mut mapped = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            if let Some(value) = f(&key, value) { mapped.insert(key, value); }
+        }
+        return mapped;
+    }
+
+    fn find_key<'a, F: Fn(&K, &V) -> bool>(&'a self, f: F) -> Option<&'a K> {
+        self.iter().find(|&(key, value)| f(key, value)).map(|(key, _)| key)
+    }
+
+    fn partition<F: Fn(&K, &V) -> bool>(self, f: F) -> (HashMap<K, V, S>, HashMap<K, V, S>) {
+        let mut passed = HashMap::with_hasher(self.hasher().clone());
+        let mut failed = HashMap::with_hasher(self.hasher().clone());
+        for (key, value) in self.into_iter() {
+            if f(&key, &value) { passed.insert(key, value); } else { failed.insert(key, value); }
+        }
+        return (passed, failed);
+    }
 }
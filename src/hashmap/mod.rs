@@ -1,10 +1,16 @@
+use std::hash::Hash;
 use std::collections::HashMap;
+use error::{InvertError, CollisionError};
+use merge::Mergeable;
+use std::collections::hash_map::RandomState;
 
 pub mod expands;
 
-/// UnderscoreHashMap expands HashMap
-pub trait HashMapU<K, V> {
+/// UnderscoreHashMap expands HashMap.
+/// The returned maps keep the BuildHasher of the original HashMap.
+pub trait HashMapU<K, V, S = RandomState> {
     /// Returns a copy of the hashmap where the keys have become the values and the values the keys.
+    /// If several keys share the same value, only one of them is kept. See invert_multi and try_invert.
     /// # Example
     ///
     /// ```
@@ -18,7 +24,40 @@ pub trait HashMapU<K, V> {
     /// let inverted = sample.invert();
     /// // => HashMap { 1usize: 1isize, 2usize: 2isize }
     /// ```
-    fn invert(self) -> HashMap<V, K>;
+    fn invert(self) -> HashMap<V, K, S> where V: Eq + Hash;
+
+    /// Same as invert, but keeps every key when several keys share the same value.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 1usize);
+    /// sample.insert(3isize, 3usize);
+    ///
+    /// let inverted = sample.invert_multi();
+    /// // => HashMap { 1usize: vec!(1isize, 2isize), 3usize: vec!(3isize) }
+    /// ```
+    fn invert_multi(self) -> HashMap<V, Vec<K>, S> where V: Eq + Hash;
+
+    /// Same as invert, but returns InvertError listing the colliding keys when several keys share the same value.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 1usize);
+    ///
+    /// let error = sample.try_invert().unwrap_err();
+    /// assert_eq!(1usize, error.collisions[0].0);
+    /// ```
+    fn try_invert(self) -> Result<HashMap<V, K, S>, InvertError<K, V>> where V: Eq + Hash;
 
     /// Return a copy of the hashmap, filtered to only have values for the whitelisted keys.
     /// # Example
@@ -34,7 +73,7 @@ pub trait HashMapU<K, V> {
     /// let picked = sample.pick(vec!(1isize));
     /// // => HashMap { 1usize: 1isize }
     /// ```
-    fn pick(self, keys: Vec<K>) -> HashMap<K, V>;
+    fn pick(self, keys: Vec<K>) -> HashMap<K, V, S>;
 
     /// Return a copy of the hashmap, filtered to only have values for the vector whitelisted keys.
     /// pick_by_filter filters keys to pick by function.
@@ -57,7 +96,7 @@ pub trait HashMapU<K, V> {
     /// let picked = sample.pick_by_filter(sample_filter);
     /// // => HashMap { 1usize: 1isize }
     /// ```
-    fn pick_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V>;
+    fn pick_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V, S>;
 
     /// Return a copy of the hashmap, filtered to omit the blacklisted keys (or array of keys).
     /// # Example
@@ -73,7 +112,7 @@ pub trait HashMapU<K, V> {
     /// let omitted = sample.omit(vec!(1isize));
     /// // => HashMap { 2usize: 2isize }
     /// ```
-    fn omit(self, keys: Vec<K>) -> HashMap<K, V>;
+    fn omit(self, keys: Vec<K>) -> HashMap<K, V, S>;
 
     /// Return a copy of the hashmap, filtered to omit the blacklisted keys (or array of keys).
     /// omit_by_filter filters keys to pick by function.
@@ -96,7 +135,7 @@ pub trait HashMapU<K, V> {
     /// let inverted = sample.omit_by_filter(sample_filter);
     /// // => HashMap { 2usize: 2isize }
     /// ```
-    fn omit_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V>;
+    fn omit_by_filter<F: Fn(&K, &V) -> bool>(self, f: F) -> HashMap<K, V, S>;
 
     /// Fill in undefined properties in hashmap with the first value present in the following list of defaults objects.
     /// # Example
@@ -116,7 +155,279 @@ pub trait HashMapU<K, V> {
     /// let defaults = origin.defaults(appends);
     /// // => HashMap { 1isize: 1usize, 2isize: 2usize, 3isize: 3usize }
     /// ```
-    fn defaults(self, appends: HashMap<K, V>) -> HashMap<K, V>;
+    fn defaults(self, appends: HashMap<K, V, S>) -> HashMap<K, V, S>;
+
+    /// Copy all of the entries of other into the hashmap, overwriting the existing ones.
+    /// This is _.extend of underscore.js.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut origin = HashMap::new();
+    /// origin.insert(1isize, 1usize);
+    /// origin.insert(2isize, 2usize);
+    ///
+    /// let mut other = HashMap::new();
+    /// other.insert(1isize, 10000usize);
+    /// other.insert(3isize, 3usize);
+    ///
+    /// let assigned = origin.assign(other);
+    /// // => HashMap { 1isize: 10000usize, 2isize: 2usize, 3isize: 3usize }
+    /// ```
+    fn assign(self, other: HashMap<K, V, S>) -> HashMap<K, V, S>;
+
+    /// Same as defaults, but takes several fallback maps. Earlier fallbacks win over later ones.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut origin = HashMap::new();
+    /// origin.insert(1isize, 1usize);
+    /// origin.insert(2isize, 2usize);
+    ///
+    /// let mut first = HashMap::new();
+    /// first.insert(3isize, 3usize);
+    /// let mut second = HashMap::new();
+    /// second.insert(3isize, 10000usize);
+    /// second.insert(4isize, 4usize);
+    ///
+    /// let defaults = origin.defaults_many(vec!(first, second));
+    /// // => HashMap { 1isize: 1usize, 2isize: 2usize, 3isize: 3usize, 4isize: 4usize }
+    /// ```
+    fn defaults_many(self, fallbacks: Vec<HashMap<K, V, S>>) -> HashMap<K, V, S>;
+
+    /// Copy all of the entries of other into the hashmap. When a key exists in both,
+    /// f is called with the key, the existing value and the value of other, and its result is kept.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut origin = HashMap::new();
+    /// origin.insert(1isize, 1usize);
+    /// origin.insert(2isize, 2usize);
+    ///
+    /// let mut other = HashMap::new();
+    /// other.insert(1isize, 10usize);
+    ///
+    /// let merged = origin.merge_with(other, |_, a, b| a + b);
+    /// // => HashMap { 1isize: 11usize, 2isize: 2usize }
+    /// ```
+    fn merge_with<F: Fn(&K, V, V) -> V>(self, other: HashMap<K, V, S>, f: F) -> HashMap<K, V, S>;
+
+    /// Merges other into the hashmap recursively. Nested maps are merged key by key, other values are overwritten.
+    /// See Mergeable.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut origin = HashMap::new();
+    /// origin.insert("db", HashMap::new());
+    /// origin.get_mut("db").unwrap().insert("host", "localhost");
+    /// origin.get_mut("db").unwrap().insert("port", "5432");
+    ///
+    /// let mut other = HashMap::new();
+    /// other.insert("db", HashMap::new());
+    /// other.get_mut("db").unwrap().insert("host", "db.example.com");
+    ///
+    /// let merged = origin.deep_merge(other);
+    /// assert_eq!("5432", merged["db"]["port"]);
+    /// ```
+    fn deep_merge(self, other: HashMap<K, V, S>) -> HashMap<K, V, S> where V: Mergeable;
+
+    /// Returns the entry with the largest value, or None if the hashmap is empty.
+    /// If several entries tie, any of them may be returned, as the iteration order of the hashmap is random.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    ///
+    /// assert_eq!(Some((&"larry", &50usize)), scores.max_by_value());
+    /// ```
+    fn max_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord;
+
+    /// Returns the entry with the smallest value, or None if the hashmap is empty.
+    /// If several entries tie, any of them may be returned, as the iteration order of the hashmap is random.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    ///
+    /// assert_eq!(Some((&"moe", &40usize)), scores.min_by_value());
+    /// ```
+    fn min_by_value<'a>(&'a self) -> Option<(&'a K, &'a V)> where V: Ord;
+
+    /// Returns the entry for which f returns the largest result, or None if the hashmap is empty.
+    /// If several entries tie, any of them may be returned, as the iteration order of the hashmap is random.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut ages = HashMap::new();
+    /// ages.insert("moe", 40isize);
+    /// ages.insert("larry", 50isize);
+    ///
+    /// assert_eq!(Some((&"larry", &50isize)), ages.max_entry_by(|name, _| name.len()));
+    /// ```
+    fn max_entry_by<'a, B: Ord, F: Fn(&K, &V) -> B>(&'a self, f: F) -> Option<(&'a K, &'a V)>;
+
+    /// Returns the entries sorted by value in ascending order.
+    /// Entries with the same value come out in random order, as the iteration order of the hashmap is random.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    /// scores.insert("curly", 10usize);
+    ///
+    /// assert_eq!(vec!(("curly", 10usize), ("moe", 40), ("larry", 50)), scores.sort_by_value());
+    /// ```
+    fn sort_by_value(self) -> Vec<(K, V)> where V: Ord;
+
+    /// Returns the n entries for which f returns the largest results, in descending order.
+    /// Entries with the same result come out in random order, and when they tie at the n-th place,
+    /// any of them may be kept.
+    /// Only n entries are kept in a heap at a time, so the whole hashmap is never sorted.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("moe", 40usize);
+    /// scores.insert("larry", 50usize);
+    /// scores.insert("curly", 10usize);
+    ///
+    /// assert_eq!(vec!(("larry", 50usize), ("moe", 40)), scores.top_n(2, |_, &score| score));
+    /// ```
+    fn top_n<B: Ord, F: Fn(&K, &V) -> B>(self, n: usize, f: F) -> Vec<(K, V)>;
+
+    /// Returns a HashMap with the same keys, where each value is the result of f. Same as _.mapObject.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert("start", 5isize);
+    /// sample.insert("end", 12isize);
+    ///
+    /// let mapped = sample.map_values(|_, value| value + 5);
+    /// assert_eq!(17isize, mapped["end"]);
+    /// ```
+    fn map_values<W, F: Fn(&K, V) -> W>(self, f: F) -> HashMap<K, W, S>;
+
+    /// Returns a HashMap where each key is the result of f.
+    /// Returns CollisionError if several keys are mapped to the same key. Use map_keys_with to combine their values.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert("moe", 40usize);
+    /// sample.insert("larry", 50usize);
+    ///
+    /// let mapped = sample.clone().map_keys(|key, _| key.len()).unwrap();
+    /// assert_eq!(50usize, mapped[&5usize]);
+    /// assert!(sample.map_keys(|_, &age| age > 30).is_err());
+    /// ```
+    fn map_keys<J, F: Fn(&K, &V) -> J>(self, f: F) -> Result<HashMap<J, V, S>, CollisionError<J>> where J: Eq + Hash;
+
+    /// Same as map_keys, but the values of the keys mapped to the same key are combined by resolve.
+    /// resolve gets them in the iteration order of the hashmap, which is random,
+    /// so the result is only stable when resolve does not depend on the order, like a sum.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert("moe", 40usize);
+    /// sample.insert("Moe", 50usize);
+    ///
+    /// let mapped = sample.map_keys_with(|key, _| key.to_lowercase(), |_, a, b| a + b);
+    /// assert_eq!(90usize, mapped["moe"]);
+    /// ```
+    fn map_keys_with<J, F: Fn(&K, &V) -> J, G: Fn(&J, V, V) -> V>(self, f: F, resolve: G) -> HashMap<J, V, S> where J: Eq + Hash;
+
+    /// Same as map_values, but the entries for which f returns None are removed.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert("one", "1");
+    /// sample.insert("two", "two");
+    ///
+    /// let parsed = sample.filter_map_values(|_, value| value.parse::<isize>().ok());
+    /// assert_eq!(1usize, parsed.len());
+    /// assert_eq!(1isize, parsed["one"]);
+    /// ```
+    fn filter_map_values<W, F: Fn(&K, V) -> Option<W>>(self, f: F) -> HashMap<K, W, S>;
+
+    /// Returns a key for which the predicate passes, or None. Same as _.findKey.
+    /// If several keys pass, any of them may be returned.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert("moe", 40usize);
+    /// sample.insert("larry", 50usize);
+    ///
+    /// assert_eq!(Some(&"larry"), sample.find_key(|_, &age| age > 45));
+    /// assert_eq!(None, sample.find_key(|_, &age| age > 60));
+    /// ```
+    fn find_key<'a, F: Fn(&K, &V) -> bool>(&'a self, f: F) -> Option<&'a K>;
+
+    /// Splits the hashmap into the entries which pass the predicate and the ones which do not.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::hashmap::HashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 2usize);
+    ///
+    /// let (odd, even) = sample.partition(|_, &value| value % 2 == 1);
+    /// assert_eq!(1usize, odd[&1isize]);
+    /// assert_eq!(2usize, even[&2isize]);
+    /// ```
+    fn partition<F: Fn(&K, &V) -> bool>(self, f: F) -> (HashMap<K, V, S>, HashMap<K, V, S>);
 
     // needs #![feature(unboxed_closures, unboxed_closure_sugar)] and the are still experimental
     // fn property(self, key: K) -> Box<|&:|:'static -> V>;
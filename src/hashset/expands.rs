@@ -0,0 +1,77 @@
+use std::hash::Hash;
+use std::hash::BuildHasher;
+use std::collections::HashSet;
+use std::collections::HashMap;
+use hashset::HashSetU;
+
+impl<T: Eq + Hash, S: BuildHasher + Clone> HashSetU<T, S> for HashSet<T, S> {
+
+    fn intersection_set(self, other: &HashSet<T, S>) -> HashSet<T, S> {
+        let mut intersected = HashSet::with_hasher(self.hasher().clone());
+        for element in self.into_iter() {
+            if other.contains(&element) { intersected.insert(element); }
+        }
+        return intersected;
+    }
+
+    fn difference_set(self, other: &HashSet<T, S>) -> HashSet<T, S> {
+        let mut difference = HashSet::with_hasher(self.hasher().clone());
+        for element in self.into_iter() {
+            if ! other.contains(&element) { difference.insert(element); }
+        }
+        return difference;
+    }
+
+    fn union_set(self, other: &HashSet<T, S>) -> HashSet<T, S> where T: Clone {
+        let mut union = self;
+        for element in other.iter() {
+            if ! union.contains(element) { union.insert(element.clone()); }
+        }
+        return union;
+    }
+
+    fn symmetric_difference_set(self, other: &HashSet<T, S>) -> HashSet<T, S> where T: Clone {
+        let mut symmetric = HashSet::with_hasher(self.hasher().clone());
+        for element in other.iter() {
+            if ! self.contains(element) { symmetric.insert(element.clone()); }
+        }
+        for element in self.into_iter() {
+            if ! other.contains(&element) { symmetric.insert(element); }
+        }
+        return symmetric;
+    }
+
+    fn partition<F: Fn(&T) -> bool>(self, f: F) -> (HashSet<T, S>, HashSet<T, S>) {
+        let mut passed = HashSet::with_hasher(self.hasher().clone());
+        let mut failed = HashSet::with_hasher(self.hasher().clone());
+        for element in self.into_iter() {
+            if f(&element) { passed.insert(element); } else { failed.insert(element); }
+        }
+        return (passed, failed);
+    }
+
+    fn pick_by_filter<F: Fn(&T) -> bool>(self, f: F) -> HashSet<T, S> {
+        let mut picked = HashSet::with_hasher(self.hasher().clone());
+        for element in self.into_iter() {
+            if f(&element) { picked.insert(element); }
+        }
+        return picked;
+    }
+
+    fn omit_by_filter<F: Fn(&T) -> bool>(self, f: F) -> HashSet<T, S> {
+        let mut omitted = HashSet::with_hasher(self.hasher().clone());
+        for element in self.into_iter() {
+            if ! f(&element) { omitted.insert(element); }
+        }
+        return omitted;
+    }
+
+    fn object<V, F: Fn(&T) -> V>(self, f: F) -> HashMap<T, V, S> {
+        let mut obj = HashMap::with_hasher(self.hasher().clone());
+        for element in self.into_iter() {
+            let value = f(&element);
+            obj.insert(element, value);
+        }
+        return obj;
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+
+pub mod expands;
+
+/// HashSetU expands HashSet.
+/// The returned sets and maps keep the BuildHasher of the original HashSet.
+/// intersection_set, difference_set, union_set and symmetric_difference_set consume the set and return a new one.
+/// They are suffixed so that the lazy methods of std, like HashSet::union, are not hidden.
+pub trait HashSetU<T, S = RandomState> {
+    /// Returns the values of the hashset which are also present in other.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let intersected = sample.intersection_set(&other);
+    /// // => HashSet { 2isize, 3isize }
+    /// ```
+    fn intersection_set(self, other: &HashSet<T, S>) -> HashSet<T, S>;
+
+    /// Returns the values of the hashset which are not present in other.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let difference = sample.difference_set(&other);
+    /// // => HashSet { 1isize }
+    /// ```
+    fn difference_set(self, other: &HashSet<T, S>) -> HashSet<T, S>;
+
+    /// Returns the values present in either the hashset or other.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let union = sample.union_set(&other);
+    /// // => HashSet { 1isize, 2isize, 3isize, 4isize }
+    /// ```
+    fn union_set(self, other: &HashSet<T, S>) -> HashSet<T, S> where T: Clone;
+
+    /// Returns the values present in the hashset or other, but not in both.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    /// let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    ///
+    /// let symmetric = sample.symmetric_difference_set(&other);
+    /// // => HashSet { 1isize, 4isize }
+    /// ```
+    fn symmetric_difference_set(self, other: &HashSet<T, S>) -> HashSet<T, S> where T: Clone;
+
+    /// Splits the hashset into two sets: the values which pass the truth test f, and those which do not.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let (odd, even) = sample.partition(|&v| v % 2 == 1);
+    /// // => (HashSet { 1isize, 3isize }, HashSet { 2isize })
+    /// ```
+    fn partition<F: Fn(&T) -> bool>(self, f: F) -> (HashSet<T, S>, HashSet<T, S>);
+
+    /// Return a copy of the hashset, filtered to only have values which pass the truth test f.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let picked = sample.pick_by_filter(|&v| v > 1);
+    /// // => HashSet { 2isize, 3isize }
+    /// ```
+    fn pick_by_filter<F: Fn(&T) -> bool>(self, f: F) -> HashSet<T, S>;
+
+    /// Return a copy of the hashset, filtered to omit values which pass the truth test f.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let omitted = sample.omit_by_filter(|&v| v > 1);
+    /// // => HashSet { 1isize }
+    /// ```
+    fn omit_by_filter<F: Fn(&T) -> bool>(self, f: F) -> HashSet<T, S>;
+
+    /// Converts the hashset into HashMap. Each value becomes a key and f computes the value for it.
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use underscore::hashset::HashSetU;
+    ///
+    /// let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    ///
+    /// let obj = sample.object(|&v| v * 10);
+    /// // => HashMap { 1isize: 10isize, 2isize: 20isize, 3isize: 30isize }
+    /// ```
+    fn object<V, F: Fn(&T) -> V>(self, f: F) -> HashMap<T, V, S>;
+}
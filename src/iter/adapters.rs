@@ -0,0 +1,67 @@
+use std::borrow::Borrow;
+
+/// Iterator returned by IteratorU::without.
+pub struct Without<'a, I, T: 'a> {
+    pub(crate) iter: I,
+    pub(crate) values: &'a [T],
+}
+
+impl<'a, I: Iterator, T: PartialEq> Iterator for Without<'a, I, T> where I::Item: Borrow<T> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let values = self.values;
+        self.iter.find(|element| ! values.contains(element.borrow()))
+    }
+}
+
+/// Iterator returned by IteratorU::intersection.
+pub struct Intersection<'a, I, T: 'a> {
+    pub(crate) iter: I,
+    pub(crate) intersec: &'a [T],
+}
+
+impl<'a, I: Iterator, T: PartialEq> Iterator for Intersection<'a, I, T> where I::Item: Borrow<T> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let intersec = self.intersec;
+        self.iter.find(|element| intersec.contains(element.borrow()))
+    }
+}
+
+/// Iterator returned by IteratorU::uniq.
+pub struct Uniq<I: Iterator> {
+    pub(crate) iter: I,
+    pub(crate) seen: Vec<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Uniq<I> where I::Item: PartialEq + Clone {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        for element in self.iter.by_ref() {
+            if ! self.seen.contains(&element) {
+                self.seen.push(element.clone());
+                return Some(element);
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator returned by IteratorU::reject.
+pub struct Reject<I, F> {
+    pub(crate) iter: I,
+    pub(crate) f: F,
+}
+
+impl<I: Iterator, F: FnMut(&I::Item) -> bool> Iterator for Reject<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let f = &mut self.f;
+        self.iter.find(|element| ! f(element))
+    }
+}
@@ -0,0 +1,65 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use iter::IteratorU;
+use iter::adapters::{Without, Intersection, Uniq, Reject};
+
+impl<I: Iterator> IteratorU for I {
+    fn without<'a, T: PartialEq>(self, values: &'a [T]) -> Without<'a, I, T> where I::Item: Borrow<T> {
+        Without { iter: self, values }
+    }
+
+    fn intersection<'a, T: PartialEq>(self, intersec: &'a [T]) -> Intersection<'a, I, T> where I::Item: Borrow<T> {
+        Intersection { iter: self, intersec }
+    }
+
+    fn uniq(self) -> Uniq<I> where I::Item: PartialEq + Clone {
+        Uniq { iter: self, seen: Vec::new() }
+    }
+
+    fn reject<F: FnMut(&I::Item) -> bool>(self, f: F) -> Reject<I, F> {
+        Reject { iter: self, f }
+    }
+}
+
+// An item of top_n_by. Items are ordered by score, and the earlier one is greater when the scores are equal.
+struct Ranked<B, T> {
+    score: B,
+    index: usize,
+    item: T,
+}
+
+impl<B: Ord, T> Ord for Ranked<B, T> {
+    fn cmp(&self, other: &Ranked<B, T>) -> Ordering {
+        self.score.cmp(&other.score).then(other.index.cmp(&self.index))
+    }
+}
+
+impl<B: Ord, T> PartialOrd for Ranked<B, T> {
+    fn partial_cmp(&self, other: &Ranked<B, T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<B: Ord, T> PartialEq for Ranked<B, T> {
+    fn eq(&self, other: &Ranked<B, T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<B: Ord, T> Eq for Ranked<B, T> {}
+
+// Returns the n items with the highest scores in descending order.
+// The heap never holds more than n + 1 items, so it runs in O(m log n) for m items.
+// It is sized from the length hint as well, so a huge n does not allocate or overflow.
+pub(crate) fn top_n_by<T, B: Ord, I: Iterator<Item = T>, F: Fn(&T) -> B>(iter: I, n: usize, f: F) -> Vec<T> {
+    if n == 0 { return Vec::new(); }
+
+    let mut heap = BinaryHeap::with_capacity(n.min(iter.size_hint().0).saturating_add(1));
+    for (index, item) in iter.enumerate() {
+        heap.push(Reverse(Ranked { score: f(&item), index, item }));
+        if heap.len() > n { heap.pop(); }
+    }
+    heap.into_sorted_vec().into_iter().map(|Reverse(ranked)| ranked.item).collect()
+}
@@ -0,0 +1,55 @@
+pub mod expand;
+pub mod adapters;
+
+use std::borrow::Borrow;
+use self::adapters::{Without, Intersection, Uniq, Reject};
+
+/// IteratorU expands Iterator with lazy versions of the VecU methods.
+/// Each method returns an iterator adapter, so no output collection is built. uniq keeps the items it has seen.
+pub trait IteratorU: Iterator + Sized {
+    /// Yields the items which are not in values.
+    /// The items only have to borrow as the values, so an iterator of &T takes a plain &[T].
+    /// # Example
+    /// ```
+    /// use underscore::iter::IteratorU;
+    ///
+    /// let without: Vec<isize> = vec!(1isize, 2, 2).into_iter().without(&[1isize]).collect();
+    /// assert_eq!(vec!(2isize, 2), without);
+    ///
+    /// let sample = vec!(1isize, 2, 3);
+    /// let borrowed: Vec<&isize> = sample.iter().without(&[1isize]).collect();
+    /// assert_eq!(vec!(&2isize, &3), borrowed);
+    /// ```
+    fn without<'a, T: PartialEq>(self, values: &'a [T]) -> Without<'a, Self, T> where Self::Item: Borrow<T>;
+
+    /// Yields the items which are also in intersec.
+    /// Like without, the items only have to borrow as the values of intersec.
+    /// # Example
+    /// ```
+    /// use underscore::iter::IteratorU;
+    ///
+    /// let intersected: Vec<isize> = vec!(1isize, 2, 3).into_iter().intersection(&[2isize, 3, 4]).collect();
+    /// assert_eq!(vec!(2isize, 3), intersected);
+    /// ```
+    fn intersection<'a, T: PartialEq>(self, intersec: &'a [T]) -> Intersection<'a, Self, T> where Self::Item: Borrow<T>;
+
+    /// Yields the first occurrence of each item. A clone of each yielded item is kept to compare the later ones.
+    /// # Example
+    /// ```
+    /// use underscore::iter::IteratorU;
+    ///
+    /// let uniq: Vec<isize> = vec!(0isize, 1, 1, 2, 1).into_iter().uniq().collect();
+    /// assert_eq!(vec!(0isize, 1, 2), uniq);
+    /// ```
+    fn uniq(self) -> Uniq<Self> where Self::Item: PartialEq + Clone;
+
+    /// Yields the items which do not pass the truth test f. The opposite of filter.
+    /// # Example
+    /// ```
+    /// use underscore::iter::IteratorU;
+    ///
+    /// let rejected: Vec<isize> = vec!(1isize, 2, 10).into_iter().reject(|&v| v < 10).collect();
+    /// assert_eq!(vec!(10isize), rejected);
+    /// ```
+    fn reject<F: FnMut(&Self::Item) -> bool>(self, f: F) -> Reject<Self, F>;
+}
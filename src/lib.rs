@@ -1,5 +1,34 @@
 #![crate_type="lib"]
+#![allow(clippy::needless_return, clippy::needless_lifetimes, clippy::ptr_arg)]
+#![deny(clippy::indexing_slicing, clippy::unwrap_used, clippy::expect_used)]
 
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+#[cfg(feature = "parallel")]
+extern crate rayon;
+#[cfg(feature = "rand")]
+extern crate rand;
+
+pub mod error;
+pub mod merge;
+pub mod flatten;
+pub mod compact;
 pub mod vec;
+pub mod slice;
+pub mod iter;
 pub mod hashmap;
 pub mod btreemap;
+pub mod hashset;
+pub mod btreeset;
+pub mod chain;
+pub mod value;
+pub mod function;
+pub mod template;
+pub mod string;
+pub mod random;
+#[cfg(feature = "serde")]
+pub mod object;
+#[cfg(feature = "parallel")]
+pub mod parallel;
@@ -0,0 +1,95 @@
+use std::hash::Hash;
+use std::hash::BuildHasher;
+use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+/// Mergeable is implemented by the values which can be deep merged by deep_merge of HashMapU and BTreeMapU.
+/// Maps are merged recursively, Option keeps the value which is present, and other values are replaced.
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use underscore::merge::Mergeable;
+///
+/// let mut origin = BTreeMap::new();
+/// origin.insert("db", BTreeMap::new());
+/// origin.get_mut("db").unwrap().insert("host", "localhost");
+/// origin.get_mut("db").unwrap().insert("port", "5432");
+///
+/// let mut other = BTreeMap::new();
+/// other.insert("db", BTreeMap::new());
+/// other.get_mut("db").unwrap().insert("host", "db.example.com");
+///
+/// let merged = origin.merge(other);
+/// assert_eq!("db.example.com", merged["db"]["host"]);
+/// assert_eq!("5432", merged["db"]["port"]);
+/// ```
+pub trait Mergeable {
+    /// Merges other into self. The values of other win.
+    fn merge(self, other: Self) -> Self;
+}
+
+impl<K: Eq + Hash, V: Mergeable, S: BuildHasher> Mergeable for HashMap<K, V, S> {
+    fn merge(self, other: HashMap<K, V, S>) -> HashMap<K, V, S> {
+        let mut merged = self;
+        for (key, value) in other.into_iter() {
+            let value = match merged.remove(&key) {
+                Some(origin) => origin.merge(value),
+                None => value,
+            };
+            merged.insert(key, value);
+        }
+        return merged;
+    }
+}
+
+impl<K: Ord, V: Mergeable> Mergeable for BTreeMap<K, V> {
+    fn merge(self, other: BTreeMap<K, V>) -> BTreeMap<K, V> {
+        let mut merged = self;
+        for (key, value) in other.into_iter() {
+            let value = match merged.remove(&key) {
+                Some(origin) => origin.merge(value),
+                None => value,
+            };
+            merged.insert(key, value);
+        }
+        return merged;
+    }
+}
+
+impl<T: Mergeable> Mergeable for Option<T> {
+    fn merge(self, other: Option<T>) -> Option<T> {
+        match (self, other) {
+            (Some(origin), Some(other)) => Some(origin.merge(other)),
+            (origin, None) => origin,
+            (None, other) => other,
+        }
+    }
+}
+
+impl<T> Mergeable for Vec<T> {
+    fn merge(self, other: Vec<T>) -> Vec<T> {
+        other
+    }
+}
+
+impl<'a> Mergeable for &'a str {
+    fn merge(self, other: &'a str) -> &'a str {
+        other
+    }
+}
+
+macro_rules! mergeable_by_replace {
+    ($($t:ty),*) => {
+        $(
+            impl Mergeable for $t {
+                fn merge(self, other: $t) -> $t {
+                    other
+                }
+            }
+        )*
+    }
+}
+
+mergeable_by_replace!(bool, char, String, (),
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
@@ -0,0 +1,127 @@
+//! pick, omit and defaults for structs. Needs the serde feature.
+//!
+//! Structs are converted into Value through serde, so field names are the serialized names.
+//! Integer fields become Value::Integer, so they are kept exact up to u64::MAX.
+use std::collections::BTreeMap;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde::ser::Error;
+use serde_json;
+use value::Value;
+use btreemap::BTreeMapU;
+
+/// Converts a Serialize value into Value.
+pub fn to_value<T: Serialize>(value: &T) -> Result<Value, serde_json::Error> {
+    serde_json::to_value(value).map(Value::from)
+}
+
+/// Converts Value into a Deserialize value.
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, serde_json::Error> {
+    serde_json::from_value(serde_json::Value::from(value))
+}
+
+/// Return the fields of the struct, filtered to only have the whitelisted fields.
+/// Use Value::from to turn the result into Value::Object.
+/// # Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate serde_derive;
+/// extern crate underscore;
+///
+/// use underscore::object::pick;
+/// use underscore::value::Value;
+///
+/// #[derive(Serialize)]
+/// struct User { name: String, age: u32, password: String }
+///
+/// fn main() {
+///     let user = User { name: "moe".to_string(), age: 50, password: "secret".to_string() };
+///     let picked = pick(&user, &["name", "age"]).unwrap();
+///     assert_eq!(Value::from("moe"), picked["name"]);
+///     assert_eq!(2usize, picked.len());
+/// }
+/// ```
+pub fn pick<T: Serialize>(value: &T, fields: &[&str]) -> Result<BTreeMap<String, Value>, serde_json::Error> {
+    let object = to_object(value)?;
+    Ok(object.pick(&fields.iter().map(|field| field.to_string()).collect()))
+}
+
+/// Return the fields of the struct, filtered to omit the blacklisted fields.
+/// Use Value::from to turn the result into Value::Object.
+/// # Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate serde_derive;
+/// extern crate underscore;
+///
+/// use underscore::object::omit;
+///
+/// #[derive(Serialize)]
+/// struct User { name: String, password: String }
+///
+/// fn main() {
+///     let user = User { name: "moe".to_string(), password: "secret".to_string() };
+///     let omitted = omit(&user, &["password"]).unwrap();
+///     assert!(! omitted.contains_key("password"));
+/// }
+/// ```
+pub fn omit<T: Serialize>(value: &T, fields: &[&str]) -> Result<BTreeMap<String, Value>, serde_json::Error> {
+    let object = to_object(value)?;
+    Ok(object.omit(&fields.iter().map(|field| field.to_string()).collect()))
+}
+
+/// Fill in the null fields of the struct, like None, with the fields of fallback.
+/// # Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate serde_derive;
+/// extern crate underscore;
+///
+/// use underscore::object::defaults;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Config { host: Option<String>, port: Option<u16> }
+///
+/// fn main() {
+///     let config = Config { host: Some("example.com".to_string()), port: None };
+///     let fallback = Config { host: Some("localhost".to_string()), port: Some(80) };
+///     let filled = defaults(&config, &fallback).unwrap();
+///     assert_eq!(Config { host: Some("example.com".to_string()), port: Some(80) }, filled);
+/// }
+/// ```
+pub fn defaults<T: Serialize + DeserializeOwned>(value: &T, fallback: &T) -> Result<T, serde_json::Error> {
+    match serde_json::to_value(value)? {
+        serde_json::Value::Object(partial) => defaults_json(partial, fallback),
+        _ => Err(serde_json::Error::custom("value is not serialized as an object")),
+    }
+}
+
+/// Same as defaults, but takes a partial document, such as parsed user input,
+/// and fills the missing or null fields from fallback before deserializing it.
+pub fn defaults_value<T: Serialize + DeserializeOwned>(partial: Value, fallback: &T) -> Result<T, serde_json::Error> {
+    match serde_json::Value::from(partial) {
+        serde_json::Value::Object(partial) => defaults_json(partial, fallback),
+        _ => Err(serde_json::Error::custom("partial value is not an object")),
+    }
+}
+
+fn defaults_json<T: Serialize + DeserializeOwned>(partial: serde_json::Map<String, serde_json::Value>, fallback: &T) -> Result<T, serde_json::Error> {
+    let mut filled = match serde_json::to_value(fallback)? {
+        serde_json::Value::Object(object) => object,
+        _ => return Err(serde_json::Error::custom("value is not serialized as an object")),
+    };
+    for (key, value) in partial.into_iter() {
+        if ! value.is_null() { filled.insert(key, value); }
+    }
+    serde_json::from_value(serde_json::Value::Object(filled))
+}
+
+fn to_object<T: Serialize>(value: &T) -> Result<BTreeMap<String, Value>, serde_json::Error> {
+    match to_value(value)? {
+        Value::Object(object) => Ok(object),
+        _ => Err(serde_json::Error::custom("value is not serialized as an object")),
+    }
+}
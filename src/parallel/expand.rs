@@ -0,0 +1,88 @@
+use std::cmp;
+use std::hash::Hash;
+use std::hash::BuildHasher;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use rayon;
+use rayon::prelude::*;
+use parallel::{ParVecU, ParHashMapU, ParBTreeMapU};
+
+impl<T: Send + Sync> ParVecU<T> for Vec<T> {
+    fn par_reject<F: Fn(&T) -> bool + Sync>(self, f: F) -> Vec<T> {
+        self.into_par_iter().filter(|element| ! f(element)).collect()
+    }
+
+    fn par_without(self, values: &Vec<T>) -> Vec<T> where T: PartialEq {
+        self.into_par_iter().filter(|element| ! values.contains(element)).collect()
+    }
+
+    fn par_uniq(self) -> Vec<T> where T: Eq + Hash {
+        let is_first = {
+            // Each chunk finds its own first occurrences in parallel,
+            // then they are checked against the earlier chunks in order.
+            let size = cmp::max(1, self.len() / rayon::current_num_threads());
+            let candidates: Vec<Vec<usize>> = self.par_chunks(size).enumerate().map(|(n, chunk)| {
+                let mut seen = HashSet::new();
+                let mut candidates = Vec::new();
+                for (i, element) in chunk.iter().enumerate() {
+                    if seen.insert(element) { candidates.push(n * size + i); }
+                }
+                candidates
+            }).collect();
+
+            let mut is_first = vec!(false; self.len());
+            let mut seen = HashSet::new();
+            for i in candidates.into_iter().flatten() {
+                if let (Some(element), Some(is_first)) = (self.get(i), is_first.get_mut(i)) {
+                    if seen.insert(element) { *is_first = true; }
+                }
+            }
+            is_first
+        };
+
+        let mut uniq = Vec::new();
+        for (element, is_first) in self.into_iter().zip(is_first) {
+            if is_first { uniq.push(element) }
+        }
+
+        return uniq;
+    }
+
+    fn par_group_by<K: Ord + Send, F: Fn(&T) -> K + Sync>(self, f: F) -> BTreeMap<K, Vec<T>> {
+        let keys: Vec<K> = self.par_iter().map(&f).collect();
+        let mut grouped = BTreeMap::new();
+        for (key, element) in keys.into_iter().zip(self) {
+            grouped.entry(key).or_insert_with(Vec::new).push(element);
+        }
+        return grouped;
+    }
+}
+
+impl<K: Eq + Hash + Send, V: Send, S: BuildHasher + Clone> ParHashMapU<K, V, S> for HashMap<K, V, S> {
+    fn par_pick_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> HashMap<K, V, S> {
+        let mut picked = HashMap::with_hasher(self.hasher().clone());
+        let pairs: Vec<(K, V)> = self.into_iter().collect();
+        picked.extend(pairs.into_par_iter().filter(|(key, value)| f(key, value)).collect::<Vec<_>>());
+        return picked;
+    }
+
+    fn par_omit_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> HashMap<K, V, S> {
+        let mut omitted = HashMap::with_hasher(self.hasher().clone());
+        let pairs: Vec<(K, V)> = self.into_iter().collect();
+        omitted.extend(pairs.into_par_iter().filter(|(key, value)| ! f(key, value)).collect::<Vec<_>>());
+        return omitted;
+    }
+}
+
+impl<K: Ord + Send, V: Send> ParBTreeMapU<K, V> for BTreeMap<K, V> {
+    fn par_pick_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> BTreeMap<K, V> {
+        let pairs: Vec<(K, V)> = self.into_iter().collect();
+        pairs.into_par_iter().filter(|(key, value)| f(key, value)).collect::<Vec<_>>().into_iter().collect()
+    }
+
+    fn par_omit_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> BTreeMap<K, V> {
+        let pairs: Vec<(K, V)> = self.into_iter().collect();
+        pairs.into_par_iter().filter(|(key, value)| ! f(key, value)).collect::<Vec<_>>().into_iter().collect()
+    }
+}
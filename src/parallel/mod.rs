@@ -0,0 +1,118 @@
+//! Parallel versions of the VecU, HashMapU and BTreeMapU methods. Needs the parallel feature.
+//!
+//! The results are the same as the sequential methods, including the order of Vec and BTreeMap.
+use std::hash::Hash;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+
+pub mod expand;
+
+/// ParVecU expands Vec with methods which run on the rayon thread pool.
+pub trait ParVecU<T> {
+    /// Same as VecU::reject.
+    /// # Example
+    /// ```
+    /// use underscore::parallel::ParVecU;
+    ///
+    /// let sample = vec!(1isize, 2, 10);
+    /// assert_eq!(vec!(10isize), sample.par_reject(|&v| v < 10));
+    /// ```
+    fn par_reject<F: Fn(&T) -> bool + Sync>(self, f: F) -> Vec<T>;
+
+    /// Same as VecU::without.
+    /// # Example
+    /// ```
+    /// use underscore::parallel::ParVecU;
+    ///
+    /// let sample = vec!(1isize, 2isize, 2isize);
+    /// assert_eq!(vec!(2isize, 2isize), sample.par_without(&vec!(1isize)));
+    /// ```
+    fn par_without(self, values: &Vec<T>) -> Vec<T> where T: PartialEq;
+
+    /// Same as VecU::uniq. The first occurrence of each element is kept.
+    /// # Example
+    /// ```
+    /// use underscore::parallel::ParVecU;
+    ///
+    /// let sample = vec!(3isize, 1, 3, 2, 1);
+    /// assert_eq!(vec!(3isize, 1, 2), sample.par_uniq());
+    /// ```
+    fn par_uniq(self) -> Vec<T> where T: Eq + Hash;
+
+    /// Same as VecU::group_by. f runs in parallel, and each group keeps the order of the vector.
+    /// # Example
+    /// ```
+    /// use underscore::parallel::ParVecU;
+    ///
+    /// let grouped = vec!(1isize, 2, 3, 4).par_group_by(|&v| v % 2 == 0);
+    /// assert_eq!(vec!(1isize, 3), grouped[&false]);
+    /// ```
+    fn par_group_by<K: Ord + Send, F: Fn(&T) -> K + Sync>(self, f: F) -> BTreeMap<K, Vec<T>>;
+}
+
+/// ParHashMapU expands HashMap with methods which run on the rayon thread pool.
+pub trait ParHashMapU<K, V, S = RandomState> {
+    /// Same as HashMapU::pick_by_filter.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::parallel::ParHashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 2usize);
+    ///
+    /// let picked = sample.par_pick_by_filter(|&k, _| k == 1);
+    /// // => HashMap { 1isize: 1usize }
+    /// ```
+    fn par_pick_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> HashMap<K, V, S>;
+
+    /// Same as HashMapU::omit_by_filter.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::parallel::ParHashMapU;
+    ///
+    /// let mut sample = HashMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 2usize);
+    ///
+    /// let omitted = sample.par_omit_by_filter(|&k, _| k == 1);
+    /// // => HashMap { 2isize: 2usize }
+    /// ```
+    fn par_omit_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> HashMap<K, V, S>;
+}
+
+/// ParBTreeMapU expands BTreeMap with methods which run on the rayon thread pool.
+pub trait ParBTreeMapU<K, V> {
+    /// Same as BTreeMapU::pick_by_filter.
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::parallel::ParBTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 2usize);
+    ///
+    /// let picked = sample.par_pick_by_filter(|&k, _| k == 1);
+    /// // => BTreeMap { 1isize: 1usize }
+    /// ```
+    fn par_pick_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> BTreeMap<K, V>;
+
+    /// Same as BTreeMapU::omit_by_filter.
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use underscore::parallel::ParBTreeMapU;
+    ///
+    /// let mut sample = BTreeMap::new();
+    /// sample.insert(1isize, 1usize);
+    /// sample.insert(2isize, 2usize);
+    ///
+    /// let omitted = sample.par_omit_by_filter(|&k, _| k == 1);
+    /// // => BTreeMap { 2isize: 2usize }
+    /// ```
+    fn par_omit_by_filter<F: Fn(&K, &V) -> bool + Sync>(self, f: F) -> BTreeMap<K, V>;
+}
@@ -0,0 +1,77 @@
+/// RandomSource is a source of random numbers for shuffle_with, sample and random.
+/// XorShift is built in, and rand::Rng implements it with the rand feature.
+pub trait RandomSource {
+    /// Returns the next random u64.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a uniformly distributed number in 0..n. Returns 0 if n is 0.
+    fn below(&mut self, n: u64) -> u64 {
+        if n == 0 { return 0; }
+
+        // Rejects the values of the last incomplete cycle, so every result is equally likely.
+        let limit = u64::MAX - u64::MAX % n;
+        loop {
+            let value = self.next_u64();
+            if value < limit { return value % n; }
+        }
+    }
+}
+
+/// XorShift is a small seedable PRNG (xorshift64*). It is not cryptographically secure.
+/// The same seed gives the same numbers on every platform.
+/// # Example
+/// ```
+/// use underscore::random::{XorShift, RandomSource};
+///
+/// let mut a = XorShift::new(42);
+/// let mut b = XorShift::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// Creates a generator from seed. Any seed including 0 is fine.
+    pub fn new(seed: u64) -> XorShift {
+        // splitmix64 spreads similar seeds apart, and never gives the all zero state for them.
+        let mut state = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        state ^= state >> 31;
+        XorShift { state: if state == 0 { 0x9E37_79B9_7F4A_7C15 } else { state } }
+    }
+}
+
+impl RandomSource for XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<R: ::rand::Rng> RandomSource for R {
+    fn next_u64(&mut self) -> u64 {
+        ::rand::RngCore::next_u64(self)
+    }
+}
+
+/// Returns a random integer between min and max, inclusive. min and max may be given in any order.
+/// # Example
+/// ```
+/// use underscore::random::{random, XorShift};
+///
+/// let mut rng = XorShift::new(1);
+/// let dice = random(1, 6, &mut rng);
+/// assert!(1 <= dice && dice <= 6);
+/// ```
+pub fn random<R: RandomSource>(min: i64, max: i64, rng: &mut R) -> i64 {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let span = max.wrapping_sub(min) as u64;
+    if span == u64::MAX { return rng.next_u64() as i64; }
+    min.wrapping_add(rng.below(span + 1) as i64)
+}
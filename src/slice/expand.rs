@@ -0,0 +1,59 @@
+use slice::SliceU;
+
+// Checks the input of the sorted methods when the check-sorted feature is on in debug builds.
+pub(crate) fn debug_assert_sorted<K: PartialOrd, I: Iterator<Item = K>>(keys: I) {
+    if cfg!(all(debug_assertions, feature = "check-sorted")) {
+        let mut previous = None;
+        for key in keys {
+            if let Some(previous) = previous {
+                assert!(previous <= key, "vector is not sorted");
+            }
+            previous = Some(key);
+        }
+    }
+}
+
+impl<T> SliceU<T> for [T] {
+    fn index_of(&self, value: &T) -> Option<usize> where T: PartialEq {
+        for (index, element) in self.iter().enumerate() {
+            if element.eq(value) { return Some(index) }
+        }
+
+        None
+    }
+
+    fn last_index_of(&self, value: &T) -> Option<usize> where T: PartialEq {
+        for (index, element) in self.iter().enumerate().rev() {
+            if element.eq(value) { return Some(index) }
+        }
+
+        None
+    }
+
+    fn index_of_sorted(&self, value: &T) -> Option<usize> where T: Ord {
+        let index = self.sorted_index(value);
+        match self.get(index) {
+            Some(element) if element.eq(value) => Some(index),
+            _ => None,
+        }
+    }
+
+    fn sorted_index(&self, value: &T) -> usize where T: Ord {
+        debug_assert_sorted(self.iter());
+        self.partition_point(|element| element < value)
+    }
+
+    fn sorted_index_by<K: Ord, F: Fn(&T) -> K>(&self, value: &T, f: F) -> usize {
+        debug_assert_sorted(self.iter().map(&f));
+        let key = f(value);
+        self.partition_point(|element| f(element) < key)
+    }
+
+    fn initial(&self, n: usize) -> &[T] {
+        self.split_at(self.len().saturating_sub(n)).0
+    }
+
+    fn rest(&self, n: usize) -> &[T] {
+        self.split_at(n.min(self.len())).1
+    }
+}
@@ -0,0 +1,79 @@
+pub mod expand;
+
+/// SliceU expands slices, so the methods also work on arrays and Vec without copying them.
+/// A VecDeque is not a slice: call make_contiguous on it first, which needs &mut and may move its elements,
+/// or use IteratorU on its iterator.
+pub trait SliceU<T> {
+    /// Returns the Option with index which value can be found in the slice.
+    /// # Example
+    /// ```
+    /// use underscore::slice::SliceU;
+    ///
+    /// let sample = [3isize, 2, 1];
+    /// assert_eq!(2usize, sample.index_of(&1isize).unwrap());
+    /// ```
+    fn index_of(&self, value: &T) -> Option<usize> where T: PartialEq;
+
+    /// Returns the Option with the last index which value can be found in the slice.
+    /// # Example
+    /// ```
+    /// use underscore::slice::SliceU;
+    ///
+    /// let sample = [1isize, 2, 3, 1];
+    /// assert_eq!(3usize, sample.last_index_of(&1isize).unwrap());
+    /// ```
+    fn last_index_of(&self, value: &T) -> Option<usize> where T: PartialEq;
+
+    /// Same as index_of, but uses binary search on the sorted slice.
+    /// If value appears more than once, returns the index of the first one.
+    /// # Example
+    /// ```
+    /// use underscore::slice::SliceU;
+    ///
+    /// let sample = [1isize, 2, 2, 3];
+    /// assert_eq!(1usize, sample.index_of_sorted(&2isize).unwrap());
+    /// ```
+    fn index_of_sorted(&self, value: &T) -> Option<usize> where T: Ord;
+
+    /// Uses binary search to find the lowest index at which value should be inserted into the sorted slice.
+    /// # Example
+    /// ```
+    /// use underscore::slice::SliceU;
+    ///
+    /// let sample = [10isize, 20, 30, 40, 50];
+    /// assert_eq!(3usize, sample.sorted_index(&35isize));
+    /// ```
+    fn sorted_index(&self, value: &T) -> usize where T: Ord;
+
+    /// Same as sorted_index, but the slice is sorted by the result of f.
+    /// # Example
+    /// ```
+    /// use underscore::slice::SliceU;
+    ///
+    /// let sample = [(1usize, "moe"), (2, "curly"), (3, "larry")];
+    /// assert_eq!(2usize, sample.sorted_index_by(&(3usize, "shemp"), |&(age, _)| age));
+    /// ```
+    fn sorted_index_by<K: Ord, F: Fn(&T) -> K>(&self, value: &T, f: F) -> usize;
+
+    /// Returns everything but the last n elements of the slice.
+    /// If n is larger than the length, returns an empty slice.
+    /// # Example
+    /// ```
+    /// use underscore::slice::SliceU;
+    ///
+    /// let sample = [5isize, 4, 3, 2, 1];
+    /// assert_eq!(&[5isize, 4, 3], sample.initial(2));
+    /// ```
+    fn initial(&self, n: usize) -> &[T];
+
+    /// Returns the rest of the elements after the first n elements of the slice.
+    /// If n is larger than the length, returns an empty slice.
+    /// # Example
+    /// ```
+    /// use underscore::slice::SliceU;
+    ///
+    /// let sample = [5isize, 4, 3, 2, 1];
+    /// assert_eq!(&[3isize, 2, 1], sample.rest(2));
+    /// ```
+    fn rest(&self, n: usize) -> &[T];
+}
@@ -0,0 +1,167 @@
+use std::char;
+use string::{StrU, Pad};
+
+impl<S: AsRef<str> + ?Sized> StrU for S {
+    fn escape(&self) -> String {
+        let mut escaped = String::with_capacity(self.as_ref().len());
+        for c in self.as_ref().chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#x27;"),
+                '`' => escaped.push_str("&#x60;"),
+                c => escaped.push(c),
+            }
+        }
+        return escaped;
+    }
+
+    fn unescape(&self) -> String {
+        let mut unescaped = String::with_capacity(self.as_ref().len());
+        let mut rest = self.as_ref();
+        while let Some(start) = rest.find('&') {
+            let (text, entity) = rest.split_at(start);
+            unescaped.push_str(text);
+
+            let decoded = entity.find(';').and_then(|end| {
+                let name = entity.get(1..end)?;
+                Some((decode_entity(name)?, end + 1))
+            });
+            match decoded {
+                Some((c, length)) => {
+                    unescaped.push(c);
+                    rest = entity.split_at(length).1;
+                },
+                None => {
+                    unescaped.push('&');
+                    rest = entity.split_at(1).1;
+                },
+            }
+        }
+        unescaped.push_str(rest);
+        return unescaped;
+    }
+
+    fn capitalize(&self) -> String {
+        let mut chars = self.as_ref().chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    fn camelize(&self) -> String {
+        let mut camelized = String::new();
+        let mut upper_next = false;
+        for c in self.as_ref().trim().chars() {
+            if is_separator(c) {
+                upper_next = true;
+            } else if upper_next {
+                camelized.extend(c.to_uppercase());
+                upper_next = false;
+            } else {
+                camelized.push(c);
+            }
+        }
+        return camelized;
+    }
+
+    fn underscored(&self) -> String {
+        separate(self.as_ref(), '_')
+    }
+
+    fn dasherize(&self) -> String {
+        let dasherized = separate(self.as_ref(), '-');
+        match self.as_ref().trim().chars().next() {
+            Some(first) if first.is_uppercase() => format!("-{}", dasherized),
+            _ => dasherized,
+        }
+    }
+
+    fn truncate_with(&self, length: usize, suffix: &str) -> String {
+        let mut chars = self.as_ref().chars();
+        let mut truncated: String = chars.by_ref().take(length).collect();
+        if chars.next().is_some() { truncated.push_str(suffix); }
+        return truncated;
+    }
+
+    fn words(&self) -> Vec<&str> {
+        self.as_ref().split_whitespace().collect()
+    }
+
+    fn pad(&self, length: usize, pad: char, side: Pad) -> String {
+        let padding = length.saturating_sub(self.as_ref().chars().count());
+        let (left, right) = match side {
+            Pad::Left => (padding, 0),
+            Pad::Right => (0, padding),
+            Pad::Both => (padding / 2, padding - padding / 2),
+        };
+
+        let mut padded: String = (0..left).map(|_| pad).collect();
+        padded.push_str(self.as_ref());
+        padded.extend((0..right).map(|_| pad));
+        return padded;
+    }
+
+    fn strip_tags(&self) -> String {
+        let mut stripped = String::with_capacity(self.as_ref().len());
+        let mut rest = self.as_ref();
+        while let Some(start) = rest.find('<') {
+            let (text, tag) = rest.split_at(start);
+            stripped.push_str(text);
+
+            // Same as /<\/?[^>]+>/ of underscore.string: a tag has at least one char and is closed by >.
+            let after = tag.split_at(1).1;
+            match after.find('>') {
+                Some(end) if end > 0 => rest = after.split_at(end + 1).1,
+                _ => {
+                    stripped.push('<');
+                    rest = after;
+                },
+            }
+        }
+        stripped.push_str(rest);
+        return stripped;
+    }
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix('x').or_else(|| number.strip_prefix('X')) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)
+        },
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c == '-' || c == '_' || c.is_whitespace()
+}
+
+// Lower cases the string and puts separator between words, which are split by case changes and separators.
+fn separate(text: &str, separator: char) -> String {
+    let mut separated = String::new();
+    let mut previous: Option<char> = None;
+    for c in text.trim().chars() {
+        if is_separator(c) {
+            if previous.is_some_and(|previous| ! is_separator(previous)) { separated.push(separator); }
+        } else {
+            let after_word = previous.is_some_and(|previous| previous.is_lowercase() || previous.is_numeric());
+            if c.is_uppercase() && after_word { separated.push(separator); }
+            separated.extend(c.to_lowercase());
+        }
+        previous = Some(c);
+    }
+    return separated;
+}
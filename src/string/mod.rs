@@ -0,0 +1,109 @@
+pub mod expand;
+
+/// Pad tells pad which side of the string to fill.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pad {
+    Left,
+    Right,
+    /// Fills both sides. The right side gets the extra char when the padding is odd.
+    Both,
+}
+
+/// StrU expands str and String. Lengths are counted in chars, so multibyte strings are never cut in the middle.
+pub trait StrU {
+    /// Escapes a string for insertion into HTML, replacing &, <, >, ", ' and ` characters.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("Curly, Larry &amp; Moe", "Curly, Larry & Moe".escape());
+    /// ```
+    fn escape(&self) -> String;
+
+    /// The opposite of escape. Numeric character references like &#39; and &#x27; are also unescaped.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("Curly, Larry & Moe", "Curly, Larry &amp; Moe".unescape());
+    /// ```
+    fn unescape(&self) -> String;
+
+    /// Converts the first char to upper case.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("Élan", "élan".capitalize());
+    /// ```
+    fn capitalize(&self) -> String;
+
+    /// Converts underscored or dasherized string to a camelized one.
+    /// It begins with an upper case char if the string begins with a separator.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("mozTransform", "moz-transform".camelize());
+    /// assert_eq!("MozTransform", "-moz_transform".camelize());
+    /// ```
+    fn camelize(&self) -> String;
+
+    /// Converts a camelized or dasherized string into an underscored one.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("moz_transform", "MozTransform".underscored());
+    /// ```
+    fn underscored(&self) -> String;
+
+    /// Converts a underscored or camelized string into an dasherized one.
+    /// It begins with a dash if the string begins with an upper case char, like CSS vendor prefixes.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("-moz-transform", "MozTransform".dasherize());
+    /// ```
+    fn dasherize(&self) -> String;
+
+    /// Truncates the string to length chars and appends suffix if it is longer than length.
+    /// This is truncate of underscore.string, renamed not to hide String::truncate.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("Hello...", "Hello world".truncate_with(5, "..."));
+    /// assert_eq!("Hello", "Hello".truncate_with(10, "..."));
+    /// ```
+    fn truncate_with(&self, length: usize, suffix: &str) -> String;
+
+    /// Splits the string into words separated by whitespace.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!(vec!("I", "love", "you"), "  I \t love  you ".words());
+    /// ```
+    fn words(&self) -> Vec<&str>;
+
+    /// Fills the string with pad up to length chars.
+    /// # Example
+    /// ```
+    /// use underscore::string::{StrU, Pad};
+    ///
+    /// assert_eq!("00001", "1".pad(5, '0', Pad::Left));
+    /// assert_eq!("-1--", "1".pad(4, '-', Pad::Both));
+    /// ```
+    fn pad(&self, length: usize, pad: char, side: Pad) -> String;
+
+    /// Removes HTML and XML tags from the string. A < which is not closed by > is kept as it is.
+    /// # Example
+    /// ```
+    /// use underscore::string::StrU;
+    ///
+    /// assert_eq!("a link", "a <a href=\"#\">link</a>".strip_tags());
+    /// ```
+    fn strip_tags(&self) -> String;
+}
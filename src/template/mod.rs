@@ -0,0 +1,164 @@
+use std::hash::BuildHasher;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use error::TemplateError;
+use value::Value;
+use string::StrU;
+use self::parse::Node;
+use self::parse::Expression;
+
+mod parse;
+
+/// Compiles the template with the default delimiters. Same as Template::compile.
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use underscore::template::template;
+///
+/// let hello = template("hello: <%= name %>").unwrap();
+/// let mut context = BTreeMap::new();
+/// context.insert("name".to_string(), "moe");
+/// assert_eq!("hello: moe", hello.render(&context));
+/// ```
+pub fn template(source: &str) -> Result<Template, TemplateError> {
+    Template::compile(source)
+}
+
+/// Template is a compiled template which can be rendered any number of times.
+///
+/// - `<%= path %>` inserts the value at path, like "user.name" or "items[0]".
+/// - `<%- path %>` inserts the value HTML-escaped.
+/// - `<% if path %>`, `<% else %>` and `<% end %>` render a part only when the value is truthy.
+///   Null, false, 0, "" and empty arrays and objects are falsy.
+/// - `<% each path as name %>` and `<% end %>` render a part for each element of an array or each value of an object.
+///
+/// Missing values are rendered as empty strings, and arrays are joined with ",".
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use underscore::template::Template;
+/// use underscore::value::Value;
+///
+/// let list = Template::compile("<% each people as name %><li><%- name %></li><% end %>").unwrap();
+/// let mut context = BTreeMap::new();
+/// context.insert("people".to_string(), Value::from(vec!(Value::from("moe"), Value::from("<curly>"))));
+/// assert_eq!("<li>moe</li><li>&lt;curly&gt;</li>", list.render(&context));
+/// ```
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+impl Template {
+    /// Compiles the template with the delimiters "<%" and "%>".
+    pub fn compile(source: &str) -> Result<Template, TemplateError> {
+        Template::compile_with_delimiters(source, "<%", "%>")
+    }
+
+    /// Same as compile, but with other delimiters. "=" and "-" follow the opening delimiter as usual.
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use underscore::template::Template;
+    ///
+    /// let hello = Template::compile_with_delimiters("hello: {{= name }}", "{{", "}}").unwrap();
+    /// let mut context = HashMap::new();
+    /// context.insert("name".to_string(), "moe");
+    /// assert_eq!("hello: moe", hello.render(&context));
+    /// ```
+    pub fn compile_with_delimiters(source: &str, open: &str, close: &str) -> Result<Template, TemplateError> {
+        Ok(Template { nodes: parse::parse(source, open, close)? })
+    }
+
+    /// Renders the template with the values in context.
+    pub fn render<C: Context>(&self, context: &C) -> String {
+        let mut rendered = String::new();
+        render_nodes(&self.nodes, context, &mut Vec::new(), &mut rendered);
+        return rendered;
+    }
+}
+
+/// Context is implemented by the maps which a template can be rendered with.
+pub trait Context {
+    /// Returns the value of the top level name.
+    fn lookup(&self, name: &str) -> Option<Value>;
+}
+
+impl<V: Clone + Into<Value>> Context for BTreeMap<String, V> {
+    fn lookup(&self, name: &str) -> Option<Value> {
+        self.get(name).map(|value| value.clone().into())
+    }
+}
+
+impl<V: Clone + Into<Value>, S: BuildHasher> Context for HashMap<String, V, S> {
+    fn lookup(&self, name: &str) -> Option<Value> {
+        self.get(name).map(|value| value.clone().into())
+    }
+}
+
+impl Context for Value {
+    fn lookup(&self, name: &str) -> Option<Value> {
+        self.as_object().and_then(|object| object.get(name)).cloned()
+    }
+}
+
+// scopes holds the names bound by each, the innermost last.
+fn render_nodes<C: Context>(nodes: &[Node], context: &C, scopes: &mut Vec<(String, Value)>, rendered: &mut String) {
+    for node in nodes.iter() {
+        match *node {
+            Node::Text(ref text) => rendered.push_str(text),
+            Node::Interpolate(ref expression) => rendered.push_str(&to_string(&lookup(expression, context, scopes))),
+            Node::Escape(ref expression) => rendered.push_str(&to_string(&lookup(expression, context, scopes)).escape()),
+            Node::If(ref expression, ref nodes, ref else_nodes) => {
+                if is_truthy(&lookup(expression, context, scopes)) {
+                    render_nodes(nodes, context, scopes, rendered);
+                } else {
+                    render_nodes(else_nodes, context, scopes, rendered);
+                }
+            },
+            Node::Each(ref expression, ref name, ref nodes) => {
+                let elements = match lookup(expression, context, scopes) {
+                    Value::Array(array) => array,
+                    Value::Object(object) => object.into_values().collect(),
+                    _ => Vec::new(),
+                };
+                for element in elements.into_iter() {
+                    scopes.push((name.clone(), element));
+                    render_nodes(nodes, context, scopes, rendered);
+                    scopes.pop();
+                }
+            },
+        }
+    }
+}
+
+fn lookup<C: Context>(expression: &Expression, context: &C, scopes: &[(String, Value)]) -> Value {
+    let root = match scopes.iter().rev().find(|&(name, _)| *name == expression.name) {
+        Some((_, value)) => Some(value.clone()),
+        None => context.lookup(&expression.name),
+    };
+    root.and_then(|root| root.get_segments(&expression.segments).cloned()).unwrap_or_default()
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match *value {
+        Value::Null => false,
+        Value::Bool(value) => value,
+        Value::Integer(value) => value != 0,
+        Value::Number(value) => value != 0.0 && ! value.is_nan(),
+        Value::String(ref value) => ! value.is_empty(),
+        Value::Array(ref array) => ! array.is_empty(),
+        Value::Object(ref object) => ! object.is_empty(),
+    }
+}
+
+fn to_string(value: &Value) -> String {
+    match *value {
+        Value::Null | Value::Object(_) => String::new(),
+        Value::Bool(value) => value.to_string(),
+        Value::Integer(value) => value.to_string(),
+        Value::Number(value) if value.fract() == 0.0 && value.abs() < 1e15 => (value as i64).to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::String(ref value) => value.clone(),
+        Value::Array(ref array) => array.iter().map(to_string).collect::<Vec<_>>().join(","),
+    }
+}
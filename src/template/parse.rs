@@ -0,0 +1,136 @@
+use error::{TemplateError, TemplateErrorKind};
+use value::path::{self, Segment};
+
+pub(crate) enum Node {
+    Text(String),
+    Interpolate(Expression),
+    Escape(Expression),
+    If(Expression, Vec<Node>, Vec<Node>),
+    Each(Expression, String, Vec<Node>),
+}
+
+// A path like "user.tags[0]". name is looked up in the context, and segments in the value found.
+pub(crate) struct Expression {
+    pub(crate) name: String,
+    pub(crate) segments: Vec<Segment>,
+}
+
+enum BlockKind {
+    If(Expression),
+    Each(Expression, String),
+}
+
+// An if or each block which is not closed yet.
+struct Block {
+    kind: BlockKind,
+    tag: String,
+    offset: usize,
+    nodes: Vec<Node>,
+    else_nodes: Option<Vec<Node>>,
+}
+
+pub(crate) fn parse(source: &str, open: &str, close: &str) -> Result<Vec<Node>, TemplateError> {
+    if open.is_empty() || close.is_empty() {
+        return Err(error(source, 0, TemplateErrorKind::EmptyDelimiter));
+    }
+
+    let mut root = Vec::new();
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut rest = source;
+    let mut offset = 0usize;
+    while let Some(start) = rest.find(open) {
+        let (text, tag) = rest.split_at(start);
+        push_text(current(&mut root, &mut blocks), text);
+
+        let tag_offset = offset + start;
+        let tag = tag.split_at(open.len()).1;
+        let end = match tag.find(close) {
+            Some(end) => end,
+            None => return Err(error(source, tag_offset, TemplateErrorKind::UnclosedTag)),
+        };
+        let (content, after) = tag.split_at(end);
+        rest = after.split_at(close.len()).1;
+        offset = tag_offset + open.len() + end + close.len();
+
+        if let Some(expression) = content.strip_prefix('=') {
+            let expression = parse_expression(expression).ok_or_else(|| invalid(source, tag_offset, content))?;
+            current(&mut root, &mut blocks).push(Node::Interpolate(expression));
+            continue;
+        }
+        if let Some(expression) = content.strip_prefix('-') {
+            let expression = parse_expression(expression).ok_or_else(|| invalid(source, tag_offset, content))?;
+            current(&mut root, &mut blocks).push(Node::Escape(expression));
+            continue;
+        }
+
+        let words: Vec<&str> = content.split_whitespace().collect();
+        match words.as_slice() {
+            ["if", expression] => {
+                let expression = parse_expression(expression).ok_or_else(|| invalid(source, tag_offset, content))?;
+                blocks.push(Block { kind: BlockKind::If(expression), tag: content.trim().to_string(), offset: tag_offset, nodes: Vec::new(), else_nodes: None });
+            },
+            ["each", expression, "as", name] if is_name(name) => {
+                let expression = parse_expression(expression).ok_or_else(|| invalid(source, tag_offset, content))?;
+                blocks.push(Block { kind: BlockKind::Each(expression, name.to_string()), tag: content.trim().to_string(), offset: tag_offset, nodes: Vec::new(), else_nodes: None });
+            },
+            ["else"] => match blocks.last_mut() {
+                Some(block) if matches!(block.kind, BlockKind::If(_)) && block.else_nodes.is_none() => block.else_nodes = Some(Vec::new()),
+                _ => return Err(error(source, tag_offset, TemplateErrorKind::UnexpectedTag(content.trim().to_string()))),
+            },
+            ["end"] => match blocks.pop() {
+                Some(block) => {
+                    let node = match block.kind {
+                        BlockKind::If(expression) => Node::If(expression, block.nodes, block.else_nodes.unwrap_or_default()),
+                        BlockKind::Each(expression, name) => Node::Each(expression, name, block.nodes),
+                    };
+                    current(&mut root, &mut blocks).push(node);
+                },
+                None => return Err(error(source, tag_offset, TemplateErrorKind::UnexpectedTag(content.trim().to_string()))),
+            },
+            _ => return Err(invalid(source, tag_offset, content)),
+        }
+    }
+    push_text(current(&mut root, &mut blocks), rest);
+
+    match blocks.pop() {
+        Some(block) => Err(error(source, block.offset, TemplateErrorKind::UnclosedBlock(block.tag))),
+        None => Ok(root),
+    }
+}
+
+// Returns the nodes which the next node belongs to.
+fn current<'a>(root: &'a mut Vec<Node>, blocks: &'a mut [Block]) -> &'a mut Vec<Node> {
+    match blocks.last_mut() {
+        Some(Block { else_nodes: Some(nodes), .. }) => nodes,
+        Some(block) => &mut block.nodes,
+        None => root,
+    }
+}
+
+fn push_text(nodes: &mut Vec<Node>, text: &str) {
+    if ! text.is_empty() { nodes.push(Node::Text(text.to_string())); }
+}
+
+fn parse_expression(expression: &str) -> Option<Expression> {
+    let mut segments = path::parse(expression.trim()).ok()?.into_iter();
+    match segments.next() {
+        Some(Segment::Key(ref name)) if is_name(name) => Some(Expression { name: name.clone(), segments: segments.collect() }),
+        _ => None,
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    ! name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn invalid(source: &str, offset: usize, content: &str) -> TemplateError {
+    error(source, offset, TemplateErrorKind::InvalidTag(content.trim().to_string()))
+}
+
+// Converts the byte offset into the line and the column, both starting from 1.
+fn error(source: &str, offset: usize, kind: TemplateErrorKind) -> TemplateError {
+    let before = source.get(..offset).unwrap_or(source);
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or(before).chars().count() + 1;
+    TemplateError { kind, line, column }
+}
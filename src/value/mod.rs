@@ -0,0 +1,294 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use error::PathError;
+use self::path::Segment;
+
+pub mod path;
+#[cfg(feature = "serde")]
+mod serialize;
+
+/// Value is a loosely typed value like a JSON document.
+/// Objects are BTreeMap, so the methods of BTreeMapU work on them directly.
+///
+/// Integers are held exactly in Integer, so the u64 and i64 fields of a struct survive a round trip.
+/// Number holds the other numbers as f64.
+///
+/// Value is totally ordered, so it can also be the value of an inverted map.
+/// Numbers are compared with f64::total_cmp, so NaN equals NaN and -0.0 is less than 0.0.
+/// Integer and Number are compared by their values, and an Integer is greater than an equal Number,
+/// so Value::from(1i64) and Value::from(1.0) are not equal.
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use underscore::btreemap::BTreeMapU;
+/// use underscore::value::Value;
+///
+/// let mut user = Value::Null;
+/// user.set_path("name", Value::from("moe")).unwrap();
+/// user.set_path("tags[0]", Value::from("admin")).unwrap();
+/// assert_eq!(Some(&Value::from("admin")), user.get_path("tags[0]"));
+///
+/// if let Value::Object(object) = user {
+///     let picked = object.pick(&vec!("name".to_string()));
+///     assert_eq!(1usize, picked.len());
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub enum Value {
+    #[default]
+    Null,
+    Bool(bool),
+    Integer(i128),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Returns the value at path, like "a.b[2]".
+    /// Returns None if the path does not exist or can not be parsed.
+    pub fn get_path(&self, path: &str) -> Option<&Value> {
+        match path::parse(path) {
+            Ok(segments) => self.get_segments(&segments),
+            Err(_) => None,
+        }
+    }
+
+    /// Same as get_path, but returns a mutable reference.
+    pub fn get_path_mut(&mut self, path: &str) -> Option<&mut Value> {
+        match path::parse(path) {
+            Ok(segments) => self.get_segments_mut(&segments),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns true if there is a value at path.
+    pub fn has_path(&self, path: &str) -> bool {
+        self.get_path(path).is_some()
+    }
+
+    /// Sets value at path. Missing objects are created along the way, and Null is replaced
+    /// by an object or an array as needed. An index can be at most the length of the array,
+    /// in which case the value is appended. The whole path is checked first, so nothing is changed
+    /// when it returns Err.
+    pub fn set_path(&mut self, path: &str, value: Value) -> Result<(), PathError> {
+        let segments = path::parse(path)?;
+        self.check_segments(&segments)?;
+
+        let mut current = self;
+        for (i, segment) in segments.iter().enumerate() {
+            current = match *segment {
+                Segment::Key(ref key) => {
+                    if let Value::Null = *current { *current = Value::Object(BTreeMap::new()); }
+                    match current {
+                        Value::Object(object) => object.entry(key.clone()).or_insert(Value::Null),
+                        _ => return Err(PathError::NotContainer(path::format(segments.iter().take(i)))),
+                    }
+                },
+                Segment::Index(index) => {
+                    if let Value::Null = *current { *current = Value::Array(Vec::new()); }
+                    match current {
+                        Value::Array(array) => {
+                            if index == array.len() { array.push(Value::Null); }
+                            match array.get_mut(index) {
+                                Some(element) => element,
+                                None => return Err(PathError::OutOfBounds(path::format(segments.iter().take(i + 1)))),
+                            }
+                        },
+                        _ => return Err(PathError::NotContainer(path::format(segments.iter().take(i)))),
+                    }
+                },
+            };
+        }
+
+        *current = value;
+        return Ok(());
+    }
+
+    /// Removes the value at path and returns it. Elements after a removed array element shift down.
+    /// Returns None if there is no value at path. The root can not be deleted.
+    pub fn delete_path(&mut self, path: &str) -> Option<Value> {
+        let mut segments = path::parse(path).ok()?;
+        let last = segments.pop()?;
+
+        match (last, self.get_segments_mut(&segments)?) {
+            (Segment::Key(key), Value::Object(object)) => object.remove(&key),
+            (Segment::Index(index), Value::Array(array)) => {
+                if index < array.len() { Some(array.remove(index)) } else { None }
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the BTreeMap if the value is an object.
+    pub fn as_object(&self) -> Option<&BTreeMap<String, Value>> {
+        match *self {
+            Value::Object(ref object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Returns the mutable BTreeMap if the value is an object.
+    pub fn as_object_mut(&mut self) -> Option<&mut BTreeMap<String, Value>> {
+        match *self {
+            Value::Object(ref mut object) => Some(object),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_segments(&self, segments: &[Segment]) -> Option<&Value> {
+        let mut current = self;
+        for segment in segments.iter() {
+            current = match (segment, current) {
+                (Segment::Key(key), Value::Object(object)) => object.get(key)?,
+                (Segment::Index(index), Value::Array(array)) => array.get(*index)?,
+                _ => return None,
+            };
+        }
+        return Some(current);
+    }
+
+    // Walks segments the way set_path does, without creating anything. None is a value to be created.
+    fn check_segments(&self, segments: &[Segment]) -> Result<(), PathError> {
+        let mut current = Some(self);
+        for (i, segment) in segments.iter().enumerate() {
+            current = match (segment, current) {
+                (Segment::Key(_), None) | (Segment::Key(_), Some(Value::Null)) => None,
+                (Segment::Key(key), Some(Value::Object(object))) => object.get(key),
+                (Segment::Index(0), None) | (Segment::Index(0), Some(Value::Null)) => None,
+                (Segment::Index(_), None) | (Segment::Index(_), Some(Value::Null)) => {
+                    return Err(PathError::OutOfBounds(path::format(segments.iter().take(i + 1))));
+                },
+                (Segment::Index(index), Some(Value::Array(array))) => {
+                    if *index > array.len() {
+                        return Err(PathError::OutOfBounds(path::format(segments.iter().take(i + 1))));
+                    }
+                    array.get(*index)
+                },
+                _ => return Err(PathError::NotContainer(path::format(segments.iter().take(i)))),
+            };
+        }
+        return Ok(());
+    }
+
+    fn get_segments_mut(&mut self, segments: &[Segment]) -> Option<&mut Value> {
+        let mut current = self;
+        for segment in segments.iter() {
+            current = match (segment, current) {
+                (Segment::Key(key), Value::Object(object)) => object.get_mut(key)?,
+                (Segment::Index(index), Value::Array(array)) => array.get_mut(*index)?,
+                _ => return None,
+            };
+        }
+        return Some(current);
+    }
+
+    // The order of the variants when values of different types are compared.
+    fn rank(&self) -> u8 {
+        match *self {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Integer(_) | Value::Number(_) => 2,
+            Value::String(_) => 3,
+            Value::Array(_) => 4,
+            Value::Object(_) => 5,
+        }
+    }
+}
+
+// Compares an integer with a float in the order of total_cmp. NaN is placed like total_cmp places it,
+// and the integer is greater when both are equal, so that the order stays total.
+fn cmp_integer_number(integer: i128, number: f64) -> Ordering {
+    if number.is_nan() {
+        return if number.is_sign_negative() { Ordering::Greater } else { Ordering::Less };
+    }
+    if number >= i128::MAX as f64 { return Ordering::Less; }
+    if number < i128::MIN as f64 { return Ordering::Greater; }
+
+    let whole = number.floor();
+    match integer.cmp(&(whole as i128)) {
+        Ordering::Equal if number > whole => Ordering::Less,
+        Ordering::Equal => Ordering::Greater,
+        ordering => ordering,
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+            (Value::Number(a), Value::Number(b)) => a.total_cmp(b),
+            (Value::Integer(a), Value::Number(b)) => cmp_integer_number(*a, *b),
+            (Value::Number(a), Value::Integer(b)) => cmp_integer_number(*b, *a).reverse(),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Array(a), Value::Array(b)) => a.cmp(b),
+            (Value::Object(a), Value::Object(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        Value::Bool(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Value {
+        Value::Number(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Value {
+        Value::Integer(value as i128)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Value {
+        Value::Integer(value as i128)
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(value: &'a str) -> Value {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::String(value)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(value: Vec<Value>) -> Value {
+        Value::Array(value)
+    }
+}
+
+impl From<BTreeMap<String, Value>> for Value {
+    fn from(value: BTreeMap<String, Value>) -> Value {
+        Value::Object(value)
+    }
+}
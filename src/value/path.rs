@@ -0,0 +1,71 @@
+use error::PathError;
+
+/// Segment is one step of a path. "a.b[2]" is Key("a"), Key("b"), Index(2).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses a path like "a.b[2].c". The empty path points to the root.
+pub fn parse(path: &str) -> Result<Vec<Segment>, PathError> {
+    let mut segments = Vec::new();
+    let mut chars = path.char_indices().peekable();
+    let mut expects_key = true;
+
+    while let Some(&(position, c)) = chars.peek() {
+        match c {
+            '[' => {
+                chars.next();
+                let mut index = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, ']')) => break,
+                        Some((_, digit)) if digit.is_ascii_digit() => index.push(digit),
+                        Some((position, _)) => return Err(PathError::Syntax(position)),
+                        None => return Err(PathError::Syntax(path.len())),
+                    }
+                }
+                match index.parse() {
+                    Ok(index) => segments.push(Segment::Index(index)),
+                    Err(_) => return Err(PathError::Syntax(position)),
+                }
+                expects_key = false;
+            },
+            '.' if ! expects_key => {
+                chars.next();
+                expects_key = true;
+                if chars.peek().is_none() { return Err(PathError::Syntax(path.len())); }
+            },
+            '.' | ']' => return Err(PathError::Syntax(position)),
+            _ if expects_key => {
+                let mut key = String::new();
+                while let Some(&(_, c)) = chars.peek() {
+                    if c == '.' || c == '[' || c == ']' { break; }
+                    key.push(c);
+                    chars.next();
+                }
+                segments.push(Segment::Key(key));
+                expects_key = false;
+            },
+            _ => return Err(PathError::Syntax(position)),
+        }
+    }
+
+    return Ok(segments);
+}
+
+/// Formats segments back into a path, for error messages.
+pub fn format<'a, I: IntoIterator<Item = &'a Segment>>(segments: I) -> String {
+    let mut path = String::new();
+    for segment in segments.into_iter() {
+        match *segment {
+            Segment::Key(ref key) => {
+                if ! path.is_empty() { path.push('.'); }
+                path.push_str(key);
+            },
+            Segment::Index(index) => path.push_str(&format!("[{}]", index)),
+        }
+    }
+    return path;
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde_json;
+use value::Value;
+
+// Whole numbers are written as integers, so they can be read back into integer fields.
+fn as_integer(value: f64) -> Option<i64> {
+    if value.fract() == 0.0 && value >= i64::MIN as f64 && value < i64::MAX as f64 {
+        return Some(value as i64);
+    }
+    None
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(value) => serializer.serialize_bool(*value),
+            Value::Integer(value) => match (i64::try_from(*value), u64::try_from(*value)) {
+                (Ok(integer), _) => serializer.serialize_i64(integer),
+                (_, Ok(integer)) => serializer.serialize_u64(integer),
+                _ => serializer.serialize_f64(*value as f64),
+            },
+            Value::Number(value) => match as_integer(*value) {
+                Some(integer) => serializer.serialize_i64(integer),
+                None => serializer.serialize_f64(*value),
+            },
+            Value::String(value) => serializer.serialize_str(value),
+            Value::Array(value) => value.serialize(serializer),
+            Value::Object(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(Value::from)
+    }
+}
+
+impl From<serde_json::Value> for Value {
+    fn from(value: serde_json::Value) -> Value {
+        match value {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::Bool(value) => Value::Bool(value),
+            serde_json::Value::Number(value) => match (value.as_i64(), value.as_u64()) {
+                (Some(integer), _) => Value::from(integer),
+                (_, Some(integer)) => Value::from(integer),
+                _ => value.as_f64().map_or(Value::Null, Value::Number),
+            },
+            serde_json::Value::String(value) => Value::String(value),
+            serde_json::Value::Array(value) => Value::Array(value.into_iter().map(Value::from).collect()),
+            serde_json::Value::Object(value) => {
+                let mut object = BTreeMap::new();
+                for (key, value) in value.into_iter() {
+                    object.insert(key, Value::from(value));
+                }
+                Value::Object(object)
+            },
+        }
+    }
+}
+
+impl From<Value> for serde_json::Value {
+    /// NaN and infinite numbers become null, as JSON can not hold them.
+    /// Integers outside of the i64 and u64 ranges become f64.
+    fn from(value: Value) -> serde_json::Value {
+        match value {
+            Value::Null => serde_json::Value::Null,
+            Value::Bool(value) => serde_json::Value::Bool(value),
+            Value::Integer(value) => match (i64::try_from(value), u64::try_from(value)) {
+                (Ok(integer), _) => serde_json::Value::Number(integer.into()),
+                (_, Ok(integer)) => serde_json::Value::Number(integer.into()),
+                _ => serde_json::Value::from(value as f64),
+            },
+            Value::Number(value) => match as_integer(value) {
+                Some(integer) => serde_json::Value::Number(integer.into()),
+                None => serde_json::Number::from_f64(value).map_or(serde_json::Value::Null, serde_json::Value::Number),
+            },
+            Value::String(value) => serde_json::Value::String(value),
+            Value::Array(value) => serde_json::Value::Array(value.into_iter().map(serde_json::Value::from).collect()),
+            Value::Object(value) => {
+                let mut object = serde_json::Map::new();
+                for (key, value) in value.into_iter() {
+                    object.insert(key, serde_json::Value::from(value));
+                }
+                serde_json::Value::Object(object)
+            },
+        }
+    }
+}
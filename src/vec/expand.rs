@@ -1,12 +1,92 @@
-use vec::VecU;
+use vec::{VecU, LengthPolicy, DuplicatePolicy};
+use error::ObjectError;
+use slice::SliceU;
+use slice::expand::debug_assert_sorted;
+use iter::IteratorU;
+use flatten::Flatten;
+use compact::Compact;
+use random::RandomSource;
+use std::hash::Hash;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::BTreeSet;
 
-impl<T: PartialEq + Clone + Ord> VecU<T> for Vec<T>{
+// Pairs up keys and values by the length policy.
+fn object_pairs<T, V>(keys: Vec<T>, values: Vec<V>, length: LengthPolicy) -> Result<Vec<(T, V)>, ObjectError<T>> {
+    if length == LengthPolicy::Error && keys.len() != values.len() {
+        return Err(ObjectError::LengthMismatch { keys: keys.len(), values: values.len() });
+    }
+    Ok(keys.into_iter().zip(values).collect())
+}
+
+// Pairs up every key with its value, or None if it has no value.
+fn object_pairs_fill_none<T, V>(keys: Vec<T>, values: Vec<V>) -> Vec<(T, Option<V>)> {
+    let mut values = values.into_iter();
+    keys.into_iter().map(|key| (key, values.next())).collect()
+}
+
+// The maps which object_with and its variants build.
+trait ObjectMap<K, V>: Default {
+    fn slot(&mut self, key: &K) -> Option<&mut V>;
+    fn put(&mut self, key: K, value: V);
+}
+
+impl<K: Ord, V> ObjectMap<K, V> for BTreeMap<K, V> {
+    fn slot(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+impl<K: Eq + Hash, V> ObjectMap<K, V> for HashMap<K, V> {
+    fn slot(&mut self, key: &K) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+fn object_from<K, V, M: ObjectMap<K, V>>(pairs: Vec<(K, V)>, duplicates: DuplicatePolicy) -> Result<M, ObjectError<K>> {
+    let mut obj = M::default();
+    for (key, value) in pairs.into_iter() {
+        match (obj.slot(&key), duplicates) {
+            (None, _) => obj.put(key, value),
+            (Some(_), DuplicatePolicy::First) => {},
+            (Some(slot), DuplicatePolicy::Last) => *slot = value,
+            (Some(_), DuplicatePolicy::Error) => return Err(ObjectError::DuplicateKey(key)),
+        }
+    }
+    return Ok(obj);
+}
+
+fn object_collect_from<K, V, M: ObjectMap<K, Vec<V>>>(pairs: Vec<(K, V)>) -> M {
+    let mut obj = M::default();
+    for (key, value) in pairs.into_iter() {
+        match obj.slot(&key) {
+            Some(values) => values.push(value),
+            None => obj.put(key, vec!(value)),
+        }
+    }
+    return obj;
+}
+
+impl<T> VecU<T> for Vec<T> {
     fn first<'a>(&'a self) -> Option<&'a T> {
         self[..].first()
     }
 
-    fn without(self, values: &Vec<T>) -> Vec<T> {
+    fn without(self, values: &Vec<T>) -> Vec<T> where T: PartialEq {
+        self.into_iter().without(values).collect()
+    }
+
+    fn without_hash(self, values: &Vec<T>) -> Vec<T> where T: Eq + Hash {
+        let values: HashSet<&T> = values.iter().collect();
         let mut without_elements = Vec::new();
         for element in self.into_iter() {
             if ! values.contains(&element) { without_elements.push(element) }
@@ -15,7 +95,22 @@ impl<T: PartialEq + Clone + Ord> VecU<T> for Vec<T>{
         return without_elements;
     }
 
-    fn intersection(self, intersec: &Vec<T>) -> Vec<T> {
+    fn without_ord(self, values: &Vec<T>) -> Vec<T> where T: Ord {
+        let values: BTreeSet<&T> = values.iter().collect();
+        let mut without_elements = Vec::new();
+        for element in self.into_iter() {
+            if ! values.contains(&element) { without_elements.push(element) }
+        }
+
+        return without_elements;
+    }
+
+    fn intersection(self, intersec: &Vec<T>) -> Vec<T> where T: PartialEq {
+        self.into_iter().intersection(intersec).collect()
+    }
+
+    fn intersection_hash(self, intersec: &Vec<T>) -> Vec<T> where T: Eq + Hash {
+        let intersec: HashSet<&T> = intersec.iter().collect();
         let mut intersected = Vec::new();
         for element in self.into_iter() {
             if intersec.contains(&element) { intersected.push(element) }
@@ -24,51 +119,274 @@ impl<T: PartialEq + Clone + Ord> VecU<T> for Vec<T>{
         return intersected;
     }
 
-    // FIXME: compare by Eq
-    fn uniq(self) -> Vec<T> {
-        let mut uniq = Vec::new();
+    fn intersection_ord(self, intersec: &Vec<T>) -> Vec<T> where T: Ord {
+        let intersec: BTreeSet<&T> = intersec.iter().collect();
+        let mut intersected = Vec::new();
         for element in self.into_iter() {
-            if ! uniq.contains(&element) { uniq.push(element) }
+            if intersec.contains(&element) { intersected.push(element) }
+        }
+
+        return intersected;
+    }
+
+    fn uniq(self) -> Vec<T> where T: PartialEq + Clone {
+        self.into_iter().uniq().collect()
+    }
+
+    fn uniq_hash(self) -> Vec<T> where T: Eq + Hash {
+        let is_first: Vec<bool> = {
+            let mut seen = HashSet::new();
+            self.iter().map(|element| seen.insert(element)).collect()
+        };
+
+        let mut uniq = Vec::new();
+        for (element, is_first) in self.into_iter().zip(is_first) {
+            if is_first { uniq.push(element) }
+        }
+
+        return uniq;
+    }
+
+    fn uniq_ord(self) -> Vec<T> where T: Ord {
+        let is_first: Vec<bool> = {
+            let mut seen = BTreeSet::new();
+            self.iter().map(|element| seen.insert(element)).collect()
+        };
+
+        let mut uniq = Vec::new();
+        for (element, is_first) in self.into_iter().zip(is_first) {
+            if is_first { uniq.push(element) }
         }
 
         return uniq;
     }
 
-    fn index_of(&self, value: &T) -> Option<usize> {
-        let mut index = 0usize;
+    fn index_of(&self, value: &T) -> Option<usize> where T: PartialEq {
+        self[..].index_of(value)
+    }
+
+    fn last_index_of(&self, value: &T) -> Option<usize> where T: PartialEq {
+        self[..].last_index_of(value)
+    }
+
+    fn index_of_sorted(&self, value: &T) -> Option<usize> where T: Ord {
+        self[..].index_of_sorted(value)
+    }
+
+    fn sorted_index(&self, value: &T) -> usize where T: Ord {
+        self[..].sorted_index(value)
+    }
+
+    fn sorted_index_by<K: Ord, F: Fn(&T) -> K>(&self, value: &T, f: F) -> usize {
+        self[..].sorted_index_by(value, f)
+    }
+
+    fn uniq_sorted(self) -> Vec<T> where T: Ord {
+        debug_assert_sorted(self.iter());
+        let mut uniq = self;
+        uniq.dedup();
+
+        return uniq;
+    }
+
+    fn object<V>(self, values: Vec<V>) -> Result<BTreeMap<T, V>, ObjectError<T>> where T: Ord {
+        self.object_with(values, LengthPolicy::Error, DuplicatePolicy::Error)
+    }
+
+    fn object_with<V>(self, values: Vec<V>, length: LengthPolicy, duplicates: DuplicatePolicy) -> Result<BTreeMap<T, V>, ObjectError<T>> where T: Ord {
+        object_from(object_pairs(self, values, length)?, duplicates)
+    }
+
+    fn object_collect<V>(self, values: Vec<V>, length: LengthPolicy) -> Result<BTreeMap<T, Vec<V>>, ObjectError<T>> where T: Ord {
+        Ok(object_collect_from(object_pairs(self, values, length)?))
+    }
+
+    fn object_fill_none<V>(self, values: Vec<V>, duplicates: DuplicatePolicy) -> Result<BTreeMap<T, Option<V>>, ObjectError<T>> where T: Ord {
+        object_from(object_pairs_fill_none(self, values), duplicates)
+    }
+
+    fn object_hashmap<V>(self, values: Vec<V>) -> Result<HashMap<T, V>, ObjectError<T>> where T: Eq + Hash {
+        self.object_hashmap_with(values, LengthPolicy::Error, DuplicatePolicy::Error)
+    }
+
+    fn object_hashmap_with<V>(self, values: Vec<V>, length: LengthPolicy, duplicates: DuplicatePolicy) -> Result<HashMap<T, V>, ObjectError<T>> where T: Eq + Hash {
+        object_from(object_pairs(self, values, length)?, duplicates)
+    }
+
+    fn object_hashmap_collect<V>(self, values: Vec<V>, length: LengthPolicy) -> Result<HashMap<T, Vec<V>>, ObjectError<T>> where T: Eq + Hash {
+        Ok(object_collect_from(object_pairs(self, values, length)?))
+    }
+
+    fn object_hashmap_fill_none<V>(self, values: Vec<V>, duplicates: DuplicatePolicy) -> Result<HashMap<T, Option<V>>, ObjectError<T>> where T: Eq + Hash {
+        object_from(object_pairs_fill_none(self, values), duplicates)
+    }
+
+    fn reject<F: Fn(&T) -> bool>(self, f: F) -> Vec<T> {
+        self.into_iter().reject(f).collect()
+    }
+
+    fn group_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMap<K, Vec<T>> {
+        let mut grouped = BTreeMap::new();
+        for element in self.into_iter() {
+            grouped.entry(f(&element)).or_insert_with(Vec::new).push(element);
+        }
+        return grouped;
+    }
+
+    fn group_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMap<K, Vec<T>> {
+        let mut grouped = HashMap::new();
+        for element in self.into_iter() {
+            grouped.entry(f(&element)).or_insert_with(Vec::new).push(element);
+        }
+        return grouped;
+    }
+
+    fn count_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMap<K, usize> {
+        let mut counted = BTreeMap::new();
+        for element in self.iter() {
+            *counted.entry(f(element)).or_insert(0usize) += 1;
+        }
+        return counted;
+    }
+
+    fn count_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMap<K, usize> {
+        let mut counted = HashMap::new();
         for element in self.iter() {
-            if element.eq(value) { return Some(index) }
-            index += 1;
+            *counted.entry(f(element)).or_insert(0usize) += 1;
+        }
+        return counted;
+    }
+
+    fn index_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMap<K, T> {
+        let mut indexed = BTreeMap::new();
+        for element in self.into_iter() {
+            indexed.insert(f(&element), element);
+        }
+        return indexed;
+    }
+
+    fn index_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMap<K, T> {
+        let mut indexed = HashMap::new();
+        for element in self.into_iter() {
+            indexed.insert(f(&element), element);
+        }
+        return indexed;
+    }
+
+    fn chunk(self, n: usize) -> Vec<Vec<T>> {
+        let mut chunks = Vec::new();
+        if n == 0 { return chunks; }
+
+        let mut elements = self.into_iter().peekable();
+        while elements.peek().is_some() {
+            chunks.push(elements.by_ref().take(n).collect());
         }
+        return chunks;
+    }
+
+    fn flatten(self) -> Vec<T::Leaf> where T: Flatten {
+        let mut flattened = Vec::new();
+        self.flatten_into(&mut flattened);
+        return flattened;
+    }
+
+    fn flatten_shallow(self) -> Vec<T::Item> where T: IntoIterator {
+        self.into_iter().flat_map(|element| element.into_iter()).collect()
+    }
 
-        None
+    fn zip(self, others: Vec<Vec<T>>) -> Vec<Vec<T>> {
+        let mut vectors = vec!(self);
+        vectors.extend(others);
+        vectors.unzip()
     }
 
-    fn last_index_of(&self, value: &T) -> Option<usize> {
-        let mut i = self.len();
+    fn unzip(self) -> Vec<Vec<T::Item>> where T: IntoIterator {
+        let mut groups: Vec<T::IntoIter> = self.into_iter().map(|group| group.into_iter()).collect();
+        let mut unzipped = Vec::new();
+        if groups.is_empty() { return unzipped; }
+
         loop {
-            i -= 1;
-            if self[i].eq(value) { return Some(i); }
-            if i == 0 { return None; }
+            let mut values = Vec::with_capacity(groups.len());
+            for group in groups.iter_mut() {
+                match group.next() {
+                    Some(value) => values.push(value),
+                    None => return unzipped,
+                }
+            }
+            unzipped.push(values);
         }
     }
 
-    // FIXME: If duplicate keys exist, the last value wins.
-    // FIXME: If values are shorter than keys, insert None.
-    fn object<V: Clone>(self, value: Vec<V>) -> BTreeMap<T, V> {
-        let mut obj = BTreeMap::new();
-        for i in (0usize..self.len() - 1) {
-            obj.insert(self[i].clone(), value[i].clone());
+    fn compact(self) -> Vec<T::Value> where T: Compact {
+        self.into_iter().filter_map(|element| element.into_option()).collect()
+    }
+
+    fn difference(self, others: &[&Vec<T>]) -> Vec<T> where T: Eq + Hash {
+        let values: HashSet<&T> = others.iter().flat_map(|other| other.iter()).collect();
+        let mut differences = Vec::new();
+        for element in self.into_iter() {
+            if ! values.contains(&element) { differences.push(element) }
         }
-        return obj;
+
+        return differences;
     }
 
-    fn reject<F: Fn(&T) -> bool>(self, f: F) -> Vec<T> {
-        let mut rejected = Vec::new();
+    fn union(self, others: &[&Vec<T>]) -> Vec<T> where T: Eq + Hash + Clone {
+        let mut united = self;
+        for other in others.iter() {
+            united.extend(other.iter().cloned());
+        }
+        united.uniq_hash()
+    }
+
+    fn initial(&self, n: usize) -> &[T] {
+        self[..].initial(n)
+    }
+
+    fn rest(&self, n: usize) -> &[T] {
+        self[..].rest(n)
+    }
+
+    fn intersection_all(self, others: &[&Vec<T>]) -> Vec<T> where T: Eq + Hash {
+        if others.is_empty() { return self; }
+
+        // counts[value] is the number of the leading vectors which contain value.
+        let mut counts: HashMap<&T, usize> = HashMap::new();
+        for (i, other) in others.iter().enumerate() {
+            for element in other.iter() {
+                let count = counts.entry(element).or_insert(0usize);
+                if *count == i { *count += 1; }
+            }
+        }
+
+        let mut intersected = Vec::new();
         for element in self.into_iter() {
-            if ! f(&element) { rejected.push(element) }
+            if counts.get(&element) == Some(&others.len()) { intersected.push(element) }
         }
-        return rejected;
+
+        return intersected;
+    }
+
+    fn shuffle_with<R: RandomSource>(self, rng: &mut R) -> Vec<T> {
+        let length = self.len();
+        self.sample(length, rng)
+    }
+
+    fn sample<R: RandomSource>(self, n: usize, rng: &mut R) -> Vec<T> {
+        // Fisher-Yates, stopped after the first n positions are filled.
+        let mut sampled = self;
+        let n = n.min(sampled.len());
+        for i in 0..n {
+            let j = i + rng.below((sampled.len() - i) as u64) as usize;
+            sampled.swap(i, j);
+        }
+        sampled.truncate(n);
+        return sampled;
+    }
+
+    fn sample_one<'a, R: RandomSource>(&'a self, rng: &mut R) -> Option<&'a T> {
+        if self.is_empty() { return None; }
+        self.get(rng.below(self.len() as u64) as usize)
     }
 }
 
@@ -1,7 +1,35 @@
+use std::hash::Hash;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use flatten::Flatten;
+use compact::Compact;
+use error::ObjectError;
+use random::RandomSource;
 
 pub mod expand;
 
+/// LengthPolicy tells object_with and object_collect what to do when the keys and the values have different lengths.
+/// Use object_fill_none to give None to the keys which have no value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthPolicy {
+    /// Returns ObjectError::LengthMismatch.
+    Error,
+    /// Drops the keys or the values which have no pair.
+    Truncate,
+}
+
+/// DuplicatePolicy tells object_with and object_fill_none what to do when a key appears more than once.
+/// Use object_collect to keep all the values of a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Keeps the value of the first one.
+    First,
+    /// Keeps the value of the last one.
+    Last,
+    /// Returns ObjectError::DuplicateKey.
+    Error,
+}
+
 /// UnderscoreVec expands collections::vec::Vec
 pub trait VecU<T> {
     /// Returns the first element of a vector as Option.
@@ -22,7 +50,27 @@ pub trait VecU<T> {
     /// let sample = vec!(1isize, 2isize, 2isize);
     /// assert_eq!(vec!(2isize, 2isize), sample.without(&vec!(1isize)));
     /// ```
-    fn without(self, values: &Vec<T>) -> Vec<T>;
+    fn without(self, values: &Vec<T>) -> Vec<T> where T: PartialEq;
+
+    /// Same as without, but looks up values in HashSet. It runs in O(n + m).
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2isize, 2isize);
+    /// assert_eq!(vec!(2isize, 2isize), sample.without_hash(&vec!(1isize)));
+    /// ```
+    fn without_hash(self, values: &Vec<T>) -> Vec<T> where T: Eq + Hash;
+
+    /// Same as without, but looks up values in BTreeSet. It runs in O((n + m) log m).
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2isize, 2isize);
+    /// assert_eq!(vec!(2isize, 2isize), sample.without_ord(&vec!(1isize)));
+    /// ```
+    fn without_ord(self, values: &Vec<T>) -> Vec<T> where T: Ord;
 
     /// Computes the list of values that are the intersection of argument vector.
     /// Each value in the result is present in each of the arrays.
@@ -33,7 +81,27 @@ pub trait VecU<T> {
     /// let sample = vec!(1isize, 2, 3);
     /// assert_eq!(vec!(2isize, 3), sample.intersection(&vec!(2isize, 3, 4)));
     /// ```
-    fn intersection(self, intersec: &Vec<T>) -> Vec<T>;
+    fn intersection(self, intersec: &Vec<T>) -> Vec<T> where T: PartialEq;
+
+    /// Same as intersection, but looks up values in HashSet. It runs in O(n + m).
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2, 3);
+    /// assert_eq!(vec!(2isize, 3), sample.intersection_hash(&vec!(2isize, 3, 4)));
+    /// ```
+    fn intersection_hash(self, intersec: &Vec<T>) -> Vec<T> where T: Eq + Hash;
+
+    /// Same as intersection, but looks up values in BTreeSet. It runs in O((n + m) log m).
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2, 3);
+    /// assert_eq!(vec!(2isize, 3), sample.intersection_ord(&vec!(2isize, 3, 4)));
+    /// ```
+    fn intersection_ord(self, intersec: &Vec<T>) -> Vec<T> where T: Ord;
 
     /// Produces a duplicate-free version of the vector.
     /// # Example
@@ -44,10 +112,32 @@ pub trait VecU<T> {
     /// let sample = vec!(0isize, 1, 1, 1, 2, 2, 2, 3);
     /// assert_eq!(vec!(0isize, 1, 2, 3), sample.uniq());
     /// ```
-    fn uniq(self) -> Vec<T>;
+    fn uniq(self) -> Vec<T> where T: PartialEq + Clone;
+
+    /// Same as uniq, but uses HashSet to find duplicates. It runs in O(n) and keeps the original order.
+    /// # Example
+    ///
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(3isize, 1, 3, 2, 1);
+    /// assert_eq!(vec!(3isize, 1, 2), sample.uniq_hash());
+    /// ```
+    fn uniq_hash(self) -> Vec<T> where T: Eq + Hash;
+
+    /// Same as uniq, but uses BTreeSet to find duplicates. It runs in O(n log n) and keeps the original order.
+    /// # Example
+    ///
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(3isize, 1, 3, 2, 1);
+    /// assert_eq!(vec!(3isize, 1, 2), sample.uniq_ord());
+    /// ```
+    fn uniq_ord(self) -> Vec<T> where T: Ord;
 
     /// Returns the Option with index which value can be found in the vector.
-    /// Pass true for is_sorted to use sorted vector.
+    /// Use index_of_sorted for sorted vector.
     /// # Example
     ///
     /// ```
@@ -56,7 +146,7 @@ pub trait VecU<T> {
     /// let sample = vec!(3isize, 2, 1);
     /// assert_eq!(2usize, sample.index_of(&1isize).unwrap());
     /// ```
-    fn index_of(&self, value: &T) -> Option<usize>;
+    fn index_of(&self, value: &T) -> Option<usize> where T: PartialEq;
 
     /// Returns the Option with index which value can be found in the vector.
     /// # Example
@@ -66,17 +156,137 @@ pub trait VecU<T> {
     /// let sample = vec!(1isize, 2, 3, 1);
     /// assert_eq!(3usize, sample.last_index_of(&1isize).unwrap());
     /// ```
-    fn last_index_of(&self, value: &T) -> Option<usize>;
+    fn last_index_of(&self, value: &T) -> Option<usize> where T: PartialEq;
+
+    /// Same as index_of, but uses binary search on the sorted vector.
+    /// If value appears more than once, returns the index of the first one.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2, 2, 3);
+    /// assert_eq!(1usize, sample.index_of_sorted(&2isize).unwrap());
+    /// ```
+    fn index_of_sorted(&self, value: &T) -> Option<usize> where T: Ord;
 
-    /// Converts vector into BTreeMap.
+    /// Uses binary search to find the lowest index at which value should be inserted into the sorted vector.
     /// # Example
     /// ```
     /// use underscore::vec::VecU;
     ///
-    /// let obj = vec!(0usize, 1, 2, 3).object(vec!(0isize, 1, 2, 3));
-    /// //=> BTreeMap<0usize, 0isize><1u, 1isize>...
+    /// let sample = vec!(10isize, 20, 30, 40, 50);
+    /// assert_eq!(3usize, sample.sorted_index(&35isize));
     /// ```
-    fn object<V: Clone>(self, value: Vec<V>) -> BTreeMap<T, V>;
+    fn sorted_index(&self, value: &T) -> usize where T: Ord;
+
+    /// Same as sorted_index, but the vector is sorted by the result of f.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!((1usize, "moe"), (2, "curly"), (3, "larry"));
+    /// assert_eq!(2usize, sample.sorted_index_by(&(3usize, "shemp"), |&(age, _)| age));
+    /// ```
+    fn sorted_index_by<K: Ord, F: Fn(&T) -> K>(&self, value: &T, f: F) -> usize;
+
+    /// Same as uniq, but runs in O(n) on the sorted vector.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(0isize, 1, 1, 2, 3, 3);
+    /// assert_eq!(vec!(0isize, 1, 2, 3), sample.uniq_sorted());
+    /// ```
+    fn uniq_sorted(self) -> Vec<T> where T: Ord;
+
+    /// Converts vector of keys and vector of values into BTreeMap.
+    /// Returns ObjectError if the lengths differ or a key appears more than once. Use object_with to relax them.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let obj = vec!("moe", "larry").object(vec!(30isize, 40)).unwrap();
+    /// assert_eq!(40isize, obj["larry"]);
+    /// assert!(vec!("moe", "larry").object(vec!(30isize)).is_err());
+    /// ```
+    fn object<V>(self, values: Vec<V>) -> Result<BTreeMap<T, V>, ObjectError<T>> where T: Ord;
+
+    /// Same as object, but the length mismatch and the duplicate keys are handled by the policies.
+    /// # Example
+    /// ```
+    /// use underscore::vec::{VecU, LengthPolicy, DuplicatePolicy};
+    ///
+    /// let obj = vec!("a", "b", "a", "c")
+    ///     .object_with(vec!(1isize, 2, 3), LengthPolicy::Truncate, DuplicatePolicy::Last)
+    ///     .unwrap();
+    /// assert_eq!(3isize, obj["a"]);
+    /// assert!(! obj.contains_key("c"));
+    /// ```
+    fn object_with<V>(self, values: Vec<V>, length: LengthPolicy, duplicates: DuplicatePolicy) -> Result<BTreeMap<T, V>, ObjectError<T>> where T: Ord;
+
+    /// Same as object_with, but each key maps to all of its values in order.
+    /// # Example
+    /// ```
+    /// use underscore::vec::{VecU, LengthPolicy};
+    ///
+    /// let obj = vec!("a", "b", "a").object_collect(vec!(1isize, 2, 3), LengthPolicy::Error).unwrap();
+    /// assert_eq!(vec!(1isize, 3), obj["a"]);
+    /// assert_eq!(vec!(2isize), obj["b"]);
+    /// ```
+    fn object_collect<V>(self, values: Vec<V>, length: LengthPolicy) -> Result<BTreeMap<T, Vec<V>>, ObjectError<T>> where T: Ord;
+
+    /// Same as object_with, but the keys which have no value map to None. The values which have no key are dropped.
+    /// # Example
+    /// ```
+    /// use underscore::vec::{VecU, DuplicatePolicy};
+    ///
+    /// let obj = vec!("a", "b", "c").object_fill_none(vec!(1isize, 2), DuplicatePolicy::Error).unwrap();
+    /// assert_eq!(Some(1isize), obj["a"]);
+    /// assert_eq!(None, obj["c"]);
+    /// ```
+    fn object_fill_none<V>(self, values: Vec<V>, duplicates: DuplicatePolicy) -> Result<BTreeMap<T, Option<V>>, ObjectError<T>> where T: Ord;
+
+    /// Same as object, but returns HashMap.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let obj = vec!("moe", "larry").object_hashmap(vec!(30isize, 40)).unwrap();
+    /// assert_eq!(30isize, obj["moe"]);
+    /// ```
+    fn object_hashmap<V>(self, values: Vec<V>) -> Result<HashMap<T, V>, ObjectError<T>> where T: Eq + Hash;
+
+    /// Same as object_with, but returns HashMap.
+    /// # Example
+    /// ```
+    /// use underscore::vec::{VecU, LengthPolicy, DuplicatePolicy};
+    ///
+    /// let obj = vec!("a", "a", "b")
+    ///     .object_hashmap_with(vec!(1isize, 2, 3, 4), LengthPolicy::Truncate, DuplicatePolicy::First)
+    ///     .unwrap();
+    /// assert_eq!(1isize, obj["a"]);
+    /// ```
+    fn object_hashmap_with<V>(self, values: Vec<V>, length: LengthPolicy, duplicates: DuplicatePolicy) -> Result<HashMap<T, V>, ObjectError<T>> where T: Eq + Hash;
+
+    /// Same as object_collect, but returns HashMap.
+    /// # Example
+    /// ```
+    /// use underscore::vec::{VecU, LengthPolicy};
+    ///
+    /// let obj = vec!("a", "b", "a").object_hashmap_collect(vec!(1isize, 2, 3), LengthPolicy::Error).unwrap();
+    /// assert_eq!(vec!(1isize, 3), obj["a"]);
+    /// ```
+    fn object_hashmap_collect<V>(self, values: Vec<V>, length: LengthPolicy) -> Result<HashMap<T, Vec<V>>, ObjectError<T>> where T: Eq + Hash;
+
+    /// Same as object_fill_none, but returns HashMap.
+    /// # Example
+    /// ```
+    /// use underscore::vec::{VecU, DuplicatePolicy};
+    ///
+    /// let obj = vec!("a", "b").object_hashmap_fill_none(vec!(1isize), DuplicatePolicy::Error).unwrap();
+    /// assert_eq!(None, obj["b"]);
+    /// ```
+    fn object_hashmap_fill_none<V>(self, values: Vec<V>, duplicates: DuplicatePolicy) -> Result<HashMap<T, Option<V>>, ObjectError<T>> where T: Eq + Hash;
 
     /// reject the values in Vector without the elements that the truth test (predicate) passes.
     /// The opposite of vec!().iter().filter();
@@ -88,5 +298,258 @@ pub trait VecU<T> {
     /// assert_eq!(vec!(10isize), sample.reject(|&v| v < 10));
     /// ```
     fn reject<F: Fn(&T) -> bool>(self, f: F) -> Vec<T>;
+
+    /// Splits the vector into groups keyed by the result of running each element through f.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let grouped = vec!(1isize, 2, 3, 4).group_by(|&v| v % 2 == 0);
+    /// assert_eq!(vec!(1isize, 3), grouped[&false]);
+    /// assert_eq!(vec!(2isize, 4), grouped[&true]);
+    /// ```
+    fn group_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMap<K, Vec<T>>;
+
+    /// Same as group_by, but returns HashMap.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let grouped = vec!(1isize, 2, 3, 4).group_by_hashmap(|&v| v % 2 == 0);
+    /// assert_eq!(vec!(1isize, 3), grouped[&false]);
+    /// ```
+    fn group_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMap<K, Vec<T>>;
+
+    /// Returns a count for the number of elements in each group keyed by the result of f.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let counted = vec!(1isize, 2, 3, 4, 5).count_by(|&v| v % 2 == 0);
+    /// assert_eq!(3usize, counted[&false]);
+    /// assert_eq!(2usize, counted[&true]);
+    /// ```
+    fn count_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMap<K, usize>;
+
+    /// Same as count_by, but returns HashMap.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let counted = vec!(1isize, 2, 3, 4, 5).count_by_hashmap(|&v| v % 2 == 0);
+    /// assert_eq!(3usize, counted[&false]);
+    /// ```
+    fn count_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMap<K, usize>;
+
+    /// Returns a BTreeMap keyed by the result of f. Use it when you know keys are unique.
+    /// If duplicate keys exist, the last element wins.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let indexed = vec!((1usize, "a"), (2usize, "b")).index_by(|&(id, _)| id);
+    /// assert_eq!((2usize, "b"), indexed[&2usize]);
+    /// ```
+    fn index_by<K: Ord, F: Fn(&T) -> K>(self, f: F) -> BTreeMap<K, T>;
+
+    /// Same as index_by, but returns HashMap.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let indexed = vec!((1usize, "a"), (2usize, "b")).index_by_hashmap(|&(id, _)| id);
+    /// assert_eq!((1usize, "a"), indexed[&1usize]);
+    /// ```
+    fn index_by_hashmap<K: Eq + Hash, F: Fn(&T) -> K>(self, f: F) -> HashMap<K, T>;
+
+    /// Splits the vector into groups of n elements. The last group may be shorter.
+    /// If n is 0, returns an empty vector.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2, 3, 4, 5);
+    /// assert_eq!(vec!(vec!(1isize, 2), vec!(3, 4), vec!(5)), sample.chunk(2));
+    /// ```
+    fn chunk(self, n: usize) -> Vec<Vec<T>>;
+
+    /// Flattens a nested vector to any depth.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(vec!(vec!(1isize), vec!(2)), vec!(vec!(3, 4)));
+    /// assert_eq!(vec!(1isize, 2, 3, 4), sample.flatten());
+    /// ```
+    fn flatten(self) -> Vec<T::Leaf> where T: Flatten;
+
+    /// Flattens a nested vector a single level.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(vec!(vec!(1isize), vec!(2)), vec!(vec!(3, 4)));
+    /// assert_eq!(vec!(vec!(1isize), vec!(2), vec!(3, 4)), sample.flatten_shallow());
+    /// ```
+    fn flatten_shallow(self) -> Vec<T::Item> where T: IntoIterator;
+
+    /// Merges together the values of each of the vectors with the values at the corresponding position.
+    /// The result is as long as the shortest vector. Use Iterator::zip for two vectors of different types.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let zipped = vec!(1isize, 2, 3).zip(vec!(vec!(4isize, 5, 6), vec!(7, 8, 9)));
+    /// assert_eq!(vec!(vec!(1isize, 4, 7), vec!(2, 5, 8), vec!(3, 6, 9)), zipped);
+    /// ```
+    fn zip(self, others: Vec<Vec<T>>) -> Vec<Vec<T>>;
+
+    /// The opposite of zip. Splits a vector of groups into a vector per position.
+    /// The result is as long as the shortest group.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let zipped = vec!(vec!(1isize, 4, 7), vec!(2, 5, 8), vec!(3, 6, 9));
+    /// assert_eq!(vec!(vec!(1isize, 2, 3), vec!(4, 5, 6), vec!(7, 8, 9)), zipped.unzip());
+    /// ```
+    fn unzip(self) -> Vec<Vec<T::Item>> where T: IntoIterator;
+
+    /// Returns the values inside Some or Ok, removing None and Err. See Compact.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(Some(0isize), None, Some(2));
+    /// assert_eq!(vec!(0isize, 2), sample.compact());
+    ///
+    /// let sample: Vec<Result<isize, String>> = vec!(Ok(1), Err("error".to_string()));
+    /// assert_eq!(vec!(1isize), sample.compact());
+    /// ```
+    fn compact(self) -> Vec<T::Value> where T: Compact;
+
+    /// Returns the values from the vector that are not present in any of the other vectors.
+    /// Looks up values in HashSet, so it runs in O(total length). See also the difference! macro.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2, 3, 4, 5);
+    /// assert_eq!(vec!(1isize, 3, 4), sample.difference(&[&vec!(5isize, 2), &vec!(10isize)]));
+    /// ```
+    fn difference(self, others: &[&Vec<T>]) -> Vec<T> where T: Eq + Hash;
+
+    /// Computes the union of the vector and the other vectors.
+    /// Each value is present once, in the order of its first appearance. Same as uniq_hash, it runs in O(total length).
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2, 3);
+    /// assert_eq!(vec!(1isize, 2, 3, 101, 10), sample.union(&[&vec!(101isize, 2, 1, 10), &vec!(2isize, 1)]));
+    /// ```
+    fn union(self, others: &[&Vec<T>]) -> Vec<T> where T: Eq + Hash + Clone;
+
+    /// Same as SliceU::initial.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(5isize, 4, 3, 2, 1);
+    /// assert_eq!(&[5isize, 4, 3, 2], sample.initial(1));
+    /// ```
+    fn initial(&self, n: usize) -> &[T];
+
+    /// Same as SliceU::rest.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(5isize, 4, 3, 2, 1);
+    /// assert_eq!(&[4isize, 3, 2, 1], sample.rest(1));
+    /// ```
+    fn rest(&self, n: usize) -> &[T];
+
+    /// Same as intersection, but takes any number of vectors and looks up values in HashMap.
+    /// Each value in the result is present in all of the other vectors. It runs in O(total length).
+    /// See also the intersection! macro.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    ///
+    /// let sample = vec!(1isize, 2, 3, 4);
+    /// assert_eq!(vec!(2isize, 3), sample.intersection_all(&[&vec!(2isize, 3, 4), &vec!(3isize, 2)]));
+    /// ```
+    fn intersection_all(self, others: &[&Vec<T>]) -> Vec<T> where T: Eq + Hash;
+
+    /// Returns a shuffled copy of the vector, using the Fisher-Yates shuffle.
+    /// The same seed gives the same order.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    /// use underscore::random::XorShift;
+    ///
+    /// let shuffled = vec!(1isize, 2, 3, 4).shuffle_with(&mut XorShift::new(7));
+    /// assert_eq!(shuffled, vec!(1isize, 2, 3, 4).shuffle_with(&mut XorShift::new(7)));
+    /// ```
+    fn shuffle_with<R: RandomSource>(self, rng: &mut R) -> Vec<T>;
+
+    /// Returns n random elements of the vector in random order. Returns all of them if n is larger than the length.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    /// use underscore::random::XorShift;
+    ///
+    /// let sampled = vec!(1isize, 2, 3, 4).sample(2, &mut XorShift::new(7));
+    /// assert_eq!(2usize, sampled.len());
+    /// ```
+    fn sample<R: RandomSource>(self, n: usize, rng: &mut R) -> Vec<T>;
+
+    /// Returns a random element of the vector, or None if it is empty.
+    /// # Example
+    /// ```
+    /// use underscore::vec::VecU;
+    /// use underscore::random::XorShift;
+    ///
+    /// let sample = vec!(1isize, 2, 3);
+    /// assert!(sample.contains(sample.sample_one(&mut XorShift::new(7)).unwrap()));
+    /// ```
+    fn sample_one<'a, R: RandomSource>(&'a self, rng: &mut R) -> Option<&'a T>;
 }
 
+
+/// Shorthand of VecU::intersection_all. The first vector is consumed, and the others are borrowed.
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate underscore;
+///
+/// # fn main() {
+/// let ids = intersection!(vec!(1isize, 2, 3), vec!(2isize, 3), vec!(3isize, 2, 1));
+/// assert_eq!(vec!(2isize, 3), ids);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! intersection {
+    ($first:expr $(, $other:expr)* $(,)*) => {
+        $crate::vec::VecU::intersection_all($first, &[$(&$other),*])
+    }
+}
+
+/// Shorthand of VecU::difference. The first vector is consumed, and the others are borrowed.
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate underscore;
+///
+/// # fn main() {
+/// let ids = difference!(vec!(1isize, 2, 3, 4), vec!(2isize), vec!(4isize));
+/// assert_eq!(vec!(1isize, 3), ids);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! difference {
+    ($first:expr $(, $other:expr)* $(,)*) => {
+        $crate::vec::VecU::difference($first, &[$(&$other),*])
+    }
+}
@@ -1,10 +1,32 @@
+#![allow(clippy::needless_return, clippy::useless_vec)]
+
+#[macro_use]
 extern crate underscore;
 
-use underscore::vec::VecU;
+use underscore::vec::{VecU, LengthPolicy, DuplicatePolicy};
+use underscore::slice::SliceU;
+use underscore::iter::IteratorU;
 use underscore::hashmap::HashMapU;
 use underscore::btreemap::BTreeMapU;
+use underscore::hashset::HashSetU;
+use underscore::btreeset::BTreeSetU;
+use underscore::chain::chain;
+use underscore::merge::Mergeable;
+use underscore::value::Value;
+use underscore::error::{PathError, ObjectError, TemplateError, TemplateErrorKind, CollisionError};
+use underscore::template::{template, Template};
+use underscore::string::{StrU, Pad};
+use underscore::random::{random, RandomSource, XorShift};
+use underscore::function::{once, after, before, negate, negate2, compose, partial2, partial3, partial4, memoize, memoize_with};
+use underscore::function::{throttle, throttle_with_clock, debounce_with_clock, Edge};
+use underscore::function::clock::ManualClock;
+use std::time::Duration;
+use std::panic::{self, AssertUnwindSafe};
 use std::collections::HashMap;
 use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
 
 #[test]
 fn test_vec_first() {
@@ -32,6 +54,53 @@ fn test_vec_uniq() {
     assert_eq!(vec!(0usize, 1, 2, 3), v.uniq());
 }
 
+#[test]
+fn test_vec_uniq_hash_and_ord() {
+    let v = vec!(3usize, 0, 1, 3, 1, 2, 0);
+    assert_eq!(vec!(3usize, 0, 1, 2), v.clone().uniq_hash());
+    assert_eq!(vec!(3usize, 0, 1, 2), v.clone().uniq_ord());
+    assert_eq!(v.clone().uniq(), v.uniq_hash());
+}
+
+#[test]
+fn test_vec_without_hash_and_ord() {
+    let v = vec!(1isize, 2, 3, 2, 1);
+    assert_eq!(vec!(1isize, 3, 1), v.clone().without_hash(&vec!(2isize, 4)));
+    assert_eq!(vec!(1isize, 3, 1), v.clone().without_ord(&vec!(2isize, 4)));
+    assert_eq!(v.clone().without(&vec!(1isize)), v.without_hash(&vec!(1isize)));
+}
+
+#[test]
+fn test_vec_intersection_hash_and_ord() {
+    let v = vec!(1isize, 2, 3, 2, 1);
+    assert_eq!(vec!(2isize, 3, 2), v.clone().intersection_hash(&vec!(3isize, 2)));
+    assert_eq!(vec!(2isize, 3, 2), v.clone().intersection_ord(&vec!(3isize, 2)));
+    assert_eq!(v.clone().intersection(&vec!(1isize)), v.intersection_ord(&vec!(1isize)));
+}
+
+// Hashable, but not ordered.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Color(&'static str);
+
+#[test]
+fn test_vec_hash_only_elements() {
+    let v = vec!(Color("red"), Color("blue"), Color("red"), Color("green"));
+    assert_eq!(vec!(Color("red"), Color("blue"), Color("green")), v.clone().uniq_hash());
+    assert_eq!(vec!(Color("blue"), Color("green")), v.clone().without_hash(&vec!(Color("red"))));
+    assert_eq!(vec!(Color("red"), Color("red")), v.clone().intersection_hash(&vec!(Color("red"))));
+    assert_eq!(v.clone().uniq(), v.clone().uniq_hash());
+    assert_eq!(Some(1usize), v.index_of(&Color("blue")));
+}
+
+#[test]
+fn test_vec_partial_eq_only_elements() {
+    let v = vec!(1.5f64, 2.5, 1.5);
+    assert_eq!(vec!(1.5f64, 2.5), v.clone().uniq());
+    assert_eq!(vec!(2.5f64), v.clone().without(&vec!(1.5f64)));
+    assert_eq!(vec!(1.5f64, 1.5), v.clone().intersection(&vec!(1.5f64)));
+    assert_eq!(Some(2usize), v.last_index_of(&1.5f64));
+}
+
 #[test]
 fn test_vec_index_of() {
     assert_eq!(2usize, vec!(3isize, 2, 1).index_of(&1isize).unwrap());
@@ -45,16 +114,148 @@ fn test_vec_last_index_of() {
     assert_eq!(None, vec!(3isize, 2, 1).last_index_of(&4isize));
 }
 
+#[test]
+fn test_vec_index_of_sorted() {
+    let v = vec!(1isize, 2, 2, 2, 5);
+    assert_eq!(Some(0usize), v.index_of_sorted(&1isize));
+    assert_eq!(Some(1usize), v.index_of_sorted(&2isize));
+    assert_eq!(Some(4usize), v.index_of_sorted(&5isize));
+    assert_eq!(None, v.index_of_sorted(&3isize));
+    assert_eq!(None, v.index_of_sorted(&6isize));
+    assert_eq!(None, Vec::<isize>::new().index_of_sorted(&1isize));
+}
+
+#[test]
+fn test_vec_sorted_index() {
+    let v = vec!(10isize, 20, 30, 30, 40);
+    assert_eq!(0usize, v.sorted_index(&5isize));
+    assert_eq!(2usize, v.sorted_index(&30isize));
+    assert_eq!(4usize, v.sorted_index(&35isize));
+    assert_eq!(5usize, v.sorted_index(&50isize));
+    assert_eq!(0usize, Vec::<isize>::new().sorted_index(&1isize));
+}
+
+#[test]
+fn test_vec_sorted_index_by() {
+    let v = vec!((10usize, "c"), (20, "a"), (30, "b"));
+    assert_eq!(1usize, v.sorted_index_by(&(15usize, "z"), |&(age, _)| age));
+    assert_eq!(3usize, v.sorted_index_by(&(31usize, "z"), |&(age, _)| age));
+}
+
+#[test]
+fn test_vec_uniq_sorted() {
+    assert_eq!(vec!(0usize, 1, 2, 3), vec!(0usize, 1, 1, 2, 2, 3).uniq_sorted());
+    assert_eq!(Vec::<usize>::new(), Vec::<usize>::new().uniq_sorted());
+}
+
+#[test]
+#[cfg(all(debug_assertions, feature = "check-sorted"))]
+#[should_panic(expected = "vector is not sorted")]
+fn test_vec_sorted_index_unsorted() {
+    vec!(3isize, 1, 2).sorted_index(&2isize);
+}
+
 #[test]
 fn test_vec_object() {
-    let obj = vec!(0usize, 1, 2, 3).object(vec!(0isize, 1, 2, 3));
+    let obj = vec!(0usize, 1, 2, 3).object(vec!(0isize, 1, 2, 3)).unwrap();
+    assert_eq!(4usize, obj.len());
+    for (key, value) in obj.iter() {
+        assert_eq!(*value as usize, *key);
+    }
+
+    assert_eq!(Ok(BTreeMap::new()), Vec::<usize>::new().object(Vec::<isize>::new()));
+    assert_eq!(Err(ObjectError::LengthMismatch { keys: 2, values: 1 }), vec!(0usize, 1).object(vec!(0isize)));
+    assert_eq!(Err(ObjectError::DuplicateKey(0usize)), vec!(0usize, 0).object(vec!(0isize, 1)));
+    assert_eq!(Some(&1isize), vec!(0usize, 1).object_hashmap(vec!(0isize, 1)).unwrap().get(&1usize));
+}
+
+fn object_with_both(keys: Vec<&'static str>, values: Vec<isize>, length: LengthPolicy, duplicates: DuplicatePolicy)
+    -> Result<BTreeMap<&'static str, isize>, ObjectError<&'static str>> {
+    let obj = keys.clone().object_with(values.clone(), length, duplicates);
+    let hashed = keys.object_hashmap_with(values, length, duplicates).map(|obj| obj.into_iter().collect());
+    assert_eq!(obj, hashed);
+    return obj;
+}
+
+#[test]
+fn test_vec_object_with_policies() {
+    let lengths = [LengthPolicy::Error, LengthPolicy::Truncate];
+    let duplicates = [DuplicatePolicy::First, DuplicatePolicy::Last, DuplicatePolicy::Error];
+    let keys = vec!("a", "b", "a");
+
+    for &length in lengths.iter() {
+        for &duplicate in duplicates.iter() {
+            // Same lengths: the length policy does not matter.
+            let obj = object_with_both(keys.clone(), vec!(1isize, 2, 3), length, duplicate);
+            let a = match duplicate {
+                DuplicatePolicy::First => 1isize,
+                DuplicatePolicy::Last => 3isize,
+                DuplicatePolicy::Error => {
+                    assert_eq!(Err(ObjectError::DuplicateKey("a")), obj);
+                    continue;
+                },
+            };
+            let obj = obj.unwrap();
+            assert_eq!(a, obj["a"]);
+            assert_eq!(2isize, obj["b"]);
+
+            // More values than keys: extra values are dropped unless the policy is Error.
+            let longer = object_with_both(keys.clone(), vec!(1isize, 2, 3, 4), length, duplicate);
+            match length {
+                LengthPolicy::Error => assert_eq!(Err(ObjectError::LengthMismatch { keys: 3, values: 4 }), longer),
+                LengthPolicy::Truncate => assert_eq!(Ok(obj), longer),
+            }
+        }
+
+        // Fewer values than keys.
+        let shorter = object_with_both(keys.clone(), vec!(1isize, 2), length, DuplicatePolicy::Error);
+        match length {
+            LengthPolicy::Error => assert_eq!(Err(ObjectError::LengthMismatch { keys: 3, values: 2 }), shorter),
+            LengthPolicy::Truncate => assert_eq!(vec!(("a", 1isize), ("b", 2)), shorter.unwrap().into_iter().collect::<Vec<_>>()),
+        }
+    }
+}
+
+#[test]
+fn test_vec_object_collect() {
+    let keys = vec!("a", "b", "a");
+    for &length in [LengthPolicy::Error, LengthPolicy::Truncate].iter() {
+        let obj = keys.clone().object_collect(vec!(1isize, 2, 3, 4), length);
+        let hashed = keys.clone().object_hashmap_collect(vec!(1isize, 2, 3, 4), length).map(|obj| obj.into_iter().collect());
+        assert_eq!(obj, hashed);
+        match length {
+            LengthPolicy::Error => assert_eq!(Err(ObjectError::LengthMismatch { keys: 3, values: 4 }), obj),
+            LengthPolicy::Truncate => {
+                let obj = obj.unwrap();
+                assert_eq!(vec!(1isize, 3), obj["a"]);
+                assert_eq!(vec!(2isize), obj["b"]);
+            },
+        }
+    }
+
+    let truncated = keys.clone().object_collect(vec!(1isize, 2), LengthPolicy::Truncate).unwrap();
+    assert_eq!(vec!(1isize), truncated["a"]);
+    assert_eq!(vec!(2isize), truncated["b"]);
+}
 
-    for key in 0usize..obj.len() {
-        match obj.get(&key) {
-            Some(value) => assert_eq!(*value as usize, key),
-            None => assert!(false),
+#[test]
+fn test_vec_object_fill_none() {
+    let keys = vec!("a", "b", "a", "c");
+    for &duplicate in [DuplicatePolicy::First, DuplicatePolicy::Last, DuplicatePolicy::Error].iter() {
+        let obj = keys.clone().object_fill_none(vec!(1isize, 2), duplicate);
+        let hashed = keys.clone().object_hashmap_fill_none(vec!(1isize, 2), duplicate).map(|obj| obj.into_iter().collect());
+        assert_eq!(obj, hashed);
+        match duplicate {
+            DuplicatePolicy::First => assert_eq!(Some(1isize), obj.unwrap()["a"]),
+            DuplicatePolicy::Last => assert_eq!(None, obj.unwrap()["a"]),
+            DuplicatePolicy::Error => assert_eq!(Err(ObjectError::DuplicateKey("a")), obj),
         }
     }
+
+    let filled = vec!("a", "b", "c").object_fill_none(vec!(1isize, 2, 3, 4), DuplicatePolicy::Error).unwrap();
+    assert_eq!(vec!(("a", Some(1isize)), ("b", Some(2)), ("c", Some(3))), filled.into_iter().collect::<Vec<_>>());
+    let filled = vec!("a", "b", "c").object_fill_none(vec!(1isize), DuplicatePolicy::Error).unwrap();
+    assert_eq!(None, filled["c"]);
 }
 
 #[test]
@@ -63,6 +264,472 @@ fn test_vec_reject() {
     assert_eq!(vec!(10isize), rejected);
 }
 
+#[test]
+fn test_vec_chunk() {
+    assert_eq!(vec!(vec!(1isize, 2, 3), vec!(4)), vec!(1isize, 2, 3, 4).chunk(3));
+    assert_eq!(Vec::<Vec<isize>>::new(), Vec::<isize>::new().chunk(2));
+    assert_eq!(Vec::<Vec<isize>>::new(), vec!(1isize, 2).chunk(0));
+}
+
+#[test]
+fn test_vec_flatten() {
+    let nested = vec!(vec!(vec!(1isize, 2)), vec!(), vec!(vec!(3), vec!(4, 5)));
+    assert_eq!(vec!(1isize, 2, 3, 4, 5), nested.clone().flatten());
+    assert_eq!(vec!(vec!(1isize, 2), vec!(3), vec!(4, 5)), nested.flatten_shallow());
+    assert_eq!(vec!("a", "b"), vec!(vec!("a"), vec!("b")).flatten());
+}
+
+#[test]
+fn test_vec_zip_and_unzip() {
+    let zipped = vec!("moe", "larry").zip(vec!(vec!("30", "40", "50"), vec!("true", "false")));
+    assert_eq!(vec!(vec!("moe", "30", "true"), vec!("larry", "40", "false")), zipped);
+    assert_eq!(vec!(vec!("moe", "larry"), vec!("30", "40"), vec!("true", "false")), zipped.unzip());
+    assert_eq!(vec!(vec!(1isize), vec!(2)), vec!(1isize, 2).zip(vec!()));
+    assert_eq!(Vec::<Vec<isize>>::new(), Vec::<Vec<isize>>::new().unzip());
+}
+
+#[test]
+fn test_vec_compact() {
+    assert_eq!(vec!(1isize, 2), vec!(None, Some(1isize), None, Some(2)).compact());
+    let results: Vec<Result<isize, String>> = vec!(Err("e".to_string()), Ok(3));
+    assert_eq!(vec!(3isize), results.compact());
+    assert_eq!(vec!(vec!(1isize)), vec!(Some(vec!(1isize)), None).compact());
+}
+
+#[test]
+fn test_vec_arrays_of_unordered_elements() {
+    assert_eq!(vec!(1.5f64, 2.5, 3.5), vec!(vec!(vec!(1.5f64)), vec!(vec!(2.5, 3.5))).flatten());
+    assert_eq!(vec!(vec!(1.5f64, 2.5), vec!(3.5)), vec!(1.5f64, 2.5, 3.5).chunk(2));
+    let zipped = vec!(1.5f64, 2.5).zip(vec!(vec!(3.5f64, 4.5)));
+    assert_eq!(vec!(vec!(1.5f64, 3.5), vec!(2.5, 4.5)), zipped);
+    assert_eq!(vec!(vec!(1.5f64, 2.5), vec!(3.5, 4.5)), zipped.unzip());
+    assert_eq!(vec!(0.5f64), vec!(Some(0.5f64), None).compact());
+
+    let colors = vec!(Color("red"), Color("blue"));
+    assert_eq!(vec!(Color("blue")), colors.clone().difference(&[&vec!(Color("red"))]));
+    assert_eq!(vec!(Color("red"), Color("blue"), Color("green")), colors.union(&[&vec!(Color("green"), Color("red"))]));
+}
+
+#[test]
+fn test_vec_difference_and_union() {
+    let sample = vec!(1isize, 2, 2, 3, 4);
+    assert_eq!(vec!(2isize, 2, 4), sample.clone().difference(&[&vec!(1isize), &vec!(3isize, 5)]));
+    assert_eq!(sample.clone(), sample.clone().difference(&[]));
+    assert_eq!(vec!(1isize, 2, 3, 4, 0, 5), sample.union(&[&vec!(0isize, 1), &vec!(5isize, 0)]));
+}
+
+#[test]
+fn test_vec_intersection_all_and_difference() {
+    let sample = vec!(1isize, 2, 2, 3, 4);
+    assert_eq!(vec!(2isize, 2, 3), sample.clone().intersection_all(&[&vec!(3isize, 2, 2, 5), &vec!(2isize, 3)]));
+    assert_eq!(Vec::<isize>::new(), sample.clone().intersection_all(&[&vec!(1isize), &vec!(2isize)]));
+    assert_eq!(sample.clone(), sample.clone().intersection_all(&[]));
+    assert_eq!(vec!(2isize, 2, 4), sample.clone().difference(&[&vec!(1isize), &vec!(3isize, 5)]));
+    assert_eq!(vec!(2isize, 2, 4), difference!(sample.clone(), vec!(1isize), vec!(3isize, 5)));
+
+    let a = vec!(1isize, 2, 3);
+    let b = vec!(3isize, 2);
+    assert_eq!(vec!(2isize, 3), intersection!(vec!(1isize, 2, 3, 4), a, b));
+    assert_eq!(vec!(4isize), difference!(vec!(1isize, 2, 3, 4), a, b));
+    assert_eq!(vec!(1isize), intersection!(vec!(1isize)));
+}
+
+#[test]
+fn test_vec_initial_and_rest() {
+    let sample = vec!(1isize, 2, 3);
+    assert_eq!(&[1isize, 2], sample.initial(1));
+    assert_eq!(&[3isize], sample.rest(2));
+    assert!(sample.initial(4).is_empty());
+    assert!(sample.rest(4).is_empty());
+    assert_eq!(&[2isize, 3], [1isize, 2, 3].rest(1));
+}
+
+#[test]
+fn test_vec_empty_and_singleton() {
+    let empty: Vec<isize> = Vec::new();
+    let single = vec!(1isize);
+
+    assert_eq!(None, empty.first());
+    assert_eq!(None, empty.index_of(&1isize));
+    assert_eq!(None, empty.last_index_of(&1isize));
+    assert_eq!(None, empty.index_of_sorted(&1isize));
+    assert_eq!(0usize, empty.sorted_index(&1isize));
+    assert_eq!(0usize, empty.sorted_index_by(&1isize, |&v| v));
+    assert!(empty.initial(1).is_empty());
+    assert!(empty.rest(1).is_empty());
+    assert!(empty.clone().without(&single).is_empty());
+    assert!(empty.clone().intersection(&single).is_empty());
+    assert!(empty.clone().intersection_all(&[&single]).is_empty());
+    assert!(empty.clone().uniq().is_empty());
+    assert!(empty.clone().uniq_sorted().is_empty());
+    assert!(empty.clone().chunk(1).is_empty());
+    assert!(empty.clone().zip(vec!(single.clone())).is_empty());
+    assert!(empty.clone().reject(|_| false).is_empty());
+    assert!(empty.clone().group_by(|&v| v).is_empty());
+    assert!(empty.clone().count_by(|&v| v).is_empty());
+    assert!(empty.clone().index_by(|&v| v).is_empty());
+    assert_eq!(Ok(BTreeMap::new()), empty.clone().object(Vec::<isize>::new()));
+    assert_eq!(Ok(BTreeMap::new()), empty.clone().object_with(single.clone(), LengthPolicy::Truncate, DuplicatePolicy::Error));
+    assert!(empty.clone().object(single.clone()).is_err());
+
+    assert_eq!(Some(&1isize), single.first());
+    assert_eq!(Some(0usize), single.index_of(&1isize));
+    assert_eq!(Some(0usize), single.last_index_of(&1isize));
+    assert_eq!(None, single.last_index_of(&2isize));
+    assert_eq!(Some(0usize), single.index_of_sorted(&1isize));
+    assert_eq!(1usize, single.sorted_index(&2isize));
+    assert!(single.initial(1).is_empty());
+    assert!(single.rest(1).is_empty());
+    assert_eq!(single.clone(), single.clone().uniq());
+    assert_eq!(single.clone(), single.clone().uniq_sorted());
+    assert_eq!(vec!(single.clone()), single.clone().chunk(5));
+    assert_eq!(vec!(vec!(1isize, 1)), single.clone().zip(vec!(single.clone())));
+    assert_eq!(single.clone(), single.clone().union(&[&empty]));
+    assert!(single.clone().without(&single).is_empty());
+    assert_eq!(single.clone(), single.clone().intersection_all(&[&single]));
+    assert_eq!(Ok(vec!((1isize, 1isize)).into_iter().collect()), single.clone().object(single.clone()));
+    assert_eq!(Err(ObjectError::LengthMismatch { keys: 1, values: 0 }), single.clone().object(Vec::<isize>::new()));
+}
+
+#[test]
+fn test_vec_variants_empty_and_singleton() {
+    let empty: Vec<isize> = Vec::new();
+    let single = vec!(1isize);
+
+    assert!(empty.clone().uniq_hash().is_empty());
+    assert!(empty.clone().uniq_ord().is_empty());
+    assert!(empty.clone().without_hash(&single).is_empty());
+    assert!(empty.clone().without_ord(&single).is_empty());
+    assert!(empty.clone().intersection_hash(&single).is_empty());
+    assert!(empty.clone().intersection_ord(&single).is_empty());
+    assert!(empty.clone().difference(&[&single]).is_empty());
+    assert_eq!(single.clone(), empty.clone().union(&[&single]));
+    assert!(empty.clone().group_by_hashmap(|&v| v).is_empty());
+    assert!(empty.clone().count_by_hashmap(|&v| v).is_empty());
+    assert!(empty.clone().index_by_hashmap(|&v| v).is_empty());
+    assert_eq!(Ok(HashMap::new()), empty.clone().object_hashmap(Vec::<isize>::new()));
+    assert_eq!(Ok(HashMap::new()), empty.clone().object_hashmap_with(single.clone(), LengthPolicy::Truncate, DuplicatePolicy::Error));
+    assert!(empty.clone().object_hashmap(single.clone()).is_err());
+    assert!(empty.clone().shuffle_with(&mut XorShift::new(1)).is_empty());
+    assert!(empty.clone().sample(1, &mut XorShift::new(1)).is_empty());
+    assert_eq!(None, empty.sample_one(&mut XorShift::new(1)));
+    assert!(Vec::<Vec<isize>>::new().flatten().is_empty());
+    assert!(Vec::<Vec<isize>>::new().flatten_shallow().is_empty());
+    assert!(Vec::<Vec<isize>>::new().unzip().is_empty());
+    assert!(vec!(empty.clone()).unzip().is_empty());
+    assert!(Vec::<Option<isize>>::new().compact().is_empty());
+
+    assert_eq!(single.clone(), single.clone().uniq_hash());
+    assert_eq!(single.clone(), single.clone().uniq_ord());
+    assert!(single.clone().without_hash(&single).is_empty());
+    assert!(single.clone().without_ord(&single).is_empty());
+    assert_eq!(single.clone(), single.clone().intersection_hash(&single));
+    assert_eq!(single.clone(), single.clone().intersection_ord(&single));
+    assert!(single.clone().difference(&[&single]).is_empty());
+    assert_eq!(single.clone(), single.clone().difference(&[&empty]));
+    assert_eq!(Some(&1usize), single.clone().count_by_hashmap(|&v| v).get(&1isize));
+    assert_eq!(Some(&vec!(1isize)), single.clone().group_by_hashmap(|&v| v).get(&1isize));
+    assert_eq!(Some(&1isize), single.clone().index_by_hashmap(|&v| v).get(&1isize));
+    assert_eq!(Ok(vec!((1isize, 1isize)).into_iter().collect()), single.clone().object_hashmap(single.clone()));
+    assert_eq!(Err(ObjectError::LengthMismatch { keys: 1, values: 0 }), single.clone().object_hashmap(Vec::<isize>::new()));
+    assert_eq!(single.clone(), single.clone().shuffle_with(&mut XorShift::new(1)));
+    assert_eq!(single.clone(), single.clone().sample(5, &mut XorShift::new(1)));
+    assert_eq!(Some(&1isize), single.sample_one(&mut XorShift::new(1)));
+    assert_eq!(single.clone(), vec!(single.clone()).flatten());
+    assert_eq!(vec!(single.clone()), vec!(single.clone()).unzip());
+    assert_eq!(single.clone(), vec!(Some(1isize)).compact());
+}
+
+#[test]
+fn test_set_empty_and_singleton() {
+    let empty: HashSet<isize> = HashSet::new();
+    let single: HashSet<isize> = vec!(1isize).into_iter().collect();
+    assert!(empty.clone().intersection_set(&single).is_empty());
+    assert!(empty.clone().difference_set(&single).is_empty());
+    assert_eq!(single.clone(), empty.clone().union_set(&single));
+    assert_eq!(single.clone(), empty.clone().symmetric_difference_set(&single));
+    assert_eq!((HashSet::new(), HashSet::new()), empty.clone().partition(|_| true));
+    assert!(empty.clone().pick_by_filter(|_| true).is_empty());
+    assert!(empty.clone().omit_by_filter(|_| false).is_empty());
+    assert!(empty.object(|&v| v).is_empty());
+    assert_eq!(single.clone(), single.clone().intersection_set(&single));
+    assert!(single.clone().difference_set(&single).is_empty());
+    assert!(single.clone().symmetric_difference_set(&single).is_empty());
+    assert_eq!((single.clone(), HashSet::new()), single.clone().partition(|_| true));
+    assert_eq!(Some(&2isize), single.object(|&v| v * 2).get(&1isize));
+
+    let empty: BTreeSet<isize> = BTreeSet::new();
+    let single: BTreeSet<isize> = vec!(1isize).into_iter().collect();
+    assert!(empty.clone().intersection_set(&single).is_empty());
+    assert!(empty.clone().difference_set(&single).is_empty());
+    assert_eq!(single.clone(), empty.clone().union_set(&single));
+    assert_eq!(single.clone(), empty.clone().symmetric_difference_set(&single));
+    assert_eq!((BTreeSet::new(), BTreeSet::new()), empty.clone().partition(|_| true));
+    assert!(empty.clone().pick_by_filter(|_| true).is_empty());
+    assert!(empty.clone().omit_by_filter(|_| false).is_empty());
+    assert!(empty.object(|&v| v).is_empty());
+    assert_eq!(single.clone(), single.clone().intersection_set(&single));
+    assert!(single.clone().difference_set(&single).is_empty());
+    assert!(single.clone().symmetric_difference_set(&single).is_empty());
+    assert_eq!((BTreeSet::new(), single.clone()), single.clone().partition(|_| false));
+    assert_eq!(Some(&2isize), single.object(|&v| v * 2).get(&1isize));
+}
+
+#[test]
+fn test_map_empty_and_missing_keys() {
+    let empty: HashMap<isize, isize> = HashMap::new();
+    assert!(empty.clone().invert().is_empty());
+    assert!(empty.clone().invert_multi().is_empty());
+    assert_eq!(Ok(HashMap::new()), empty.clone().try_invert());
+    assert!(empty.clone().pick(vec!(1isize)).is_empty());
+    assert!(empty.clone().omit(vec!(1isize)).is_empty());
+    assert!(empty.clone().pick_by_filter(|_, _| true).is_empty());
+    assert!(empty.clone().omit_by_filter(|_, _| false).is_empty());
+    assert!(empty.clone().defaults(HashMap::new()).is_empty());
+    assert!(empty.clone().assign(HashMap::new()).is_empty());
+    assert!(empty.clone().defaults_many(vec!()).is_empty());
+    assert!(empty.clone().merge_with(HashMap::new(), |_, a, _| a).is_empty());
+    assert_eq!(None, empty.max_by_value());
+    assert_eq!(None, empty.min_by_value());
+    assert_eq!(None, empty.max_entry_by(|_, &v| v));
+    assert_eq!(None, empty.find_key(|_, _| true));
+    assert!(empty.clone().sort_by_value().is_empty());
+    assert!(empty.clone().top_n(1, |_, &v| v).is_empty());
+    assert!(empty.clone().map_values(|_, v| v).is_empty());
+    assert_eq!(Ok(HashMap::new()), empty.clone().map_keys(|&k, _| k));
+    assert!(empty.clone().filter_map_values(|_, v| Some(v)).is_empty());
+    assert_eq!((HashMap::new(), HashMap::new()), empty.clone().partition(|_, _| true));
+    assert!(empty.deep_merge(HashMap::new()).is_empty());
+
+    let mut single = HashMap::new();
+    single.insert(1isize, 10isize);
+    assert_eq!(Some((&1isize, &10isize)), single.max_by_value());
+    assert_eq!(Some((&1isize, &10isize)), single.min_by_value());
+    assert_eq!(vec!((1isize, 10isize)), single.clone().top_n(5, |_, &v| v));
+    assert_eq!(Ok(single.clone()), single.clone().map_keys(|&k, _| k));
+    assert_eq!(single.clone(), single.clone().pick(vec!(1isize, 2)));
+    assert_eq!(single.clone(), single.clone().omit(vec!(2isize)));
+    assert_eq!(Some(&1isize), single.clone().invert().get(&10isize));
+
+    let empty: BTreeMap<isize, isize> = BTreeMap::new();
+    assert!(empty.clone().invert().is_empty());
+    assert!(empty.clone().invert_multi().is_empty());
+    assert_eq!(Ok(BTreeMap::new()), empty.clone().try_invert());
+    assert!(empty.clone().pick(&vec!(1isize)).is_empty());
+    assert!(empty.clone().omit(&vec!(1isize)).is_empty());
+    assert!(empty.clone().pick_by_filter(|_, _| true).is_empty());
+    assert!(empty.clone().omit_by_filter(|_, _| false).is_empty());
+    assert!(empty.clone().defaults(BTreeMap::new()).is_empty());
+    assert!(empty.clone().assign(BTreeMap::new()).is_empty());
+    assert!(empty.clone().defaults_many(vec!()).is_empty());
+    assert!(empty.clone().merge_with(BTreeMap::new(), |_, a, _| a).is_empty());
+    assert!(empty.pairs().is_empty());
+    assert_eq!(None, empty.max_by_value());
+    assert_eq!(None, empty.min_by_value());
+    assert_eq!(None, empty.max_entry_by(|_, &v| v));
+    assert_eq!(None, empty.find_key(|_, _| true));
+    assert!(empty.clone().sort_by_value().is_empty());
+    assert!(empty.clone().top_n(1, |_, &v| v).is_empty());
+    assert!(empty.clone().map_values(|_, v| v).is_empty());
+    assert_eq!(Ok(BTreeMap::new()), empty.clone().map_keys(|&k, _| k));
+    assert!(empty.clone().filter_map_values(|_, v| Some(v)).is_empty());
+    assert_eq!((BTreeMap::new(), BTreeMap::new()), empty.clone().partition(|_, _| true));
+    assert!(empty.deep_merge(BTreeMap::new()).is_empty());
+
+    let mut single = BTreeMap::new();
+    single.insert(1isize, 10isize);
+    assert_eq!(Some((&1isize, &10isize)), single.max_by_value());
+    assert_eq!(Some((&1isize, &10isize)), single.min_by_value());
+    assert_eq!(vec!((1isize, 10isize)), single.clone().top_n(5, |_, &v| v));
+    assert_eq!(Ok(single.clone()), single.clone().map_keys(|&k, _| k));
+    assert_eq!(single.clone(), single.clone().pick(&vec!(1isize, 2)));
+    assert_eq!(single.clone(), single.clone().omit(&vec!(2isize)));
+    assert_eq!(vec!((&1isize, &10isize)), single.pairs());
+}
+
+#[test]
+fn test_slice_index_of() {
+    let array = [3isize, 2, 1, 2];
+    assert_eq!(Some(1usize), array.index_of(&2isize));
+    assert_eq!(Some(3usize), array.last_index_of(&2isize));
+    assert_eq!(None, array.index_of(&4isize));
+
+    let slice: &[isize] = &array[1..];
+    assert_eq!(Some(0usize), slice.index_of(&2isize));
+
+    let mut deque: VecDeque<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    deque.push_front(0);
+    assert_eq!(Some(3usize), deque.make_contiguous().index_of(&3isize));
+}
+
+#[test]
+fn test_slice_sorted() {
+    let array = [10isize, 20, 20, 30];
+    assert_eq!(Some(1usize), array.index_of_sorted(&20isize));
+    assert_eq!(None, array.index_of_sorted(&25isize));
+    assert_eq!(3usize, array.sorted_index(&25isize));
+    assert_eq!(1usize, array.sorted_index_by(&15isize, |&v| v));
+}
+
+#[test]
+fn test_iter_without_and_intersection() {
+    let without: Vec<isize> = [1isize, 2, 3, 2].iter().cloned().without(&[2isize]).collect();
+    assert_eq!(vec!(1isize, 3), without);
+
+    let deque: VecDeque<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let borrowed: Vec<&isize> = deque.iter().without(&[2isize]).collect();
+    assert_eq!(vec!(&1isize, &3), borrowed);
+    let borrowed: Vec<&isize> = deque.iter().intersection(&[2isize]).collect();
+    assert_eq!(vec!(&2isize), borrowed);
+
+    let intersected: Vec<isize> = deque.into_iter().intersection(&[3isize, 1]).collect();
+    assert_eq!(vec!(1isize, 3), intersected);
+}
+
+#[test]
+fn test_iter_uniq_and_reject() {
+    let mut uniq = vec!(1isize, 1, 2, 1, 3).into_iter().uniq();
+    assert_eq!(Some(1isize), uniq.next());
+    assert_eq!(Some(2isize), uniq.next());
+    assert_eq!(Some(3isize), uniq.next());
+    assert_eq!(None, uniq.next());
+
+    let rejected: Vec<isize> = (0isize..10).reject(|&v| v % 3 != 0).collect();
+    assert_eq!(vec!(0isize, 3, 6, 9), rejected);
+}
+
+#[test]
+fn test_vec_group_by() {
+    let grouped = vec!(1isize, 2, 3, 4, 5).group_by(|&v| v % 2 == 0);
+
+    assert_eq!(2usize, grouped.len());
+    assert_eq!(vec!(1isize, 3, 5), grouped[&false]);
+    assert_eq!(vec!(2isize, 4), grouped[&true]);
+}
+
+#[test]
+fn test_vec_group_by_hashmap() {
+    let grouped = vec!(1isize, 2, 3, 4, 5).group_by_hashmap(|&v| v % 2 == 0);
+
+    assert_eq!(2usize, grouped.len());
+    assert_eq!(vec!(1isize, 3, 5), grouped[&false]);
+    assert_eq!(vec!(2isize, 4), grouped[&true]);
+}
+
+#[test]
+fn test_vec_count_by() {
+    let counted = vec!(1isize, 2, 3, 4, 5).count_by(|&v| v % 2 == 0);
+    assert_eq!(3usize, counted[&false]);
+    assert_eq!(2usize, counted[&true]);
+
+    let counted = vec!(1isize, 2, 3).count_by_hashmap(|&v| v > 1);
+    assert_eq!(1usize, counted[&false]);
+    assert_eq!(2usize, counted[&true]);
+}
+
+#[test]
+fn test_vec_index_by() {
+    let indexed = vec!((1usize, 10isize), (2, 20), (1, 30)).index_by(|&(id, _)| id);
+    assert_eq!(2usize, indexed.len());
+    assert_eq!((1usize, 30isize), indexed[&1usize]);
+    assert_eq!((2usize, 20isize), indexed[&2usize]);
+
+    let indexed = vec!((1usize, 10isize), (2, 20)).index_by_hashmap(|&(id, _)| id);
+    assert_eq!((1usize, 10isize), indexed[&1usize]);
+}
+
+#[test]
+fn test_chain_vec() {
+    let values = vec!(2isize);
+    let chained = chain(vec!(1isize, 1, 2, 3, 10, 3))
+        .without(&values)
+        .uniq()
+        .reject(|&v| v > 5)
+        .value();
+    assert_eq!(vec!(1isize, 3), chained);
+
+    let intersec = vec!(3isize, 10);
+    assert_eq!(Some(3isize), chain(vec!(1isize, 3, 10)).intersection(&intersec).first());
+    assert_eq!(Some(1usize), chain(vec!(1isize, 3, 10, 3)).uniq().index_of(&3isize));
+    assert_eq!(Some(3usize), chain(vec!(1isize, 3, 10, 3)).last_index_of(&3isize));
+    assert_eq!(None, chain(Vec::<isize>::new()).last_index_of(&3isize));
+}
+
+#[test]
+fn test_chain_unordered_elements() {
+    let values = vec!(2.5f64);
+    let chained = chain(vec!(1.5f64, 2.5, 1.5, 9.5))
+        .without(&values)
+        .uniq()
+        .reject(|&v| v > 5.0)
+        .value();
+    assert_eq!(vec!(1.5f64), chained);
+    assert_eq!(Some(1usize), chain(vec!(1.5f64, 2.5)).index_of(&2.5f64));
+
+    let grouped = chain(vec!(1.5f64, 2.5, 3.5)).group_by(|&v| v > 2.0).value();
+    assert_eq!(vec!(2.5f64, 3.5), grouped[&true]);
+    assert_eq!(vec!(Color("red")), chain(vec!(Color("red"), Color("red"))).uniq().value());
+}
+
+#[test]
+fn test_chain_vec_to_map() {
+    let grouped = chain(vec!(1isize, 2, 3, 4, 5))
+        .reject(|&v| v == 5)
+        .group_by(|&v| v % 2 == 0)
+        .value();
+    assert_eq!(vec!(1isize, 3), grouped[&false]);
+    assert_eq!(vec!(2isize, 4), grouped[&true]);
+
+    let counted = chain(vec!(1isize, 2, 3)).count_by_hashmap(|&v| v > 1).value();
+    assert_eq!(2usize, counted[&true]);
+
+    let inverted = chain(vec!(1isize, 2, 3, 4))
+        .object(vec!(10usize, 20, 30, 40))
+        .unwrap()
+        .invert()
+        .pick(&vec!(10usize, 20))
+        .value();
+    assert_eq!(1isize, inverted[&10usize]);
+    assert_eq!(2isize, inverted[&20usize]);
+}
+
+#[test]
+fn test_chain_btreemap() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    sample.insert(3isize, 3usize);
+
+    let mut appends = BTreeMap::new();
+    appends.insert(4isize, 4usize);
+
+    let pairs = chain(sample)
+        .omit(&vec!(3isize))
+        .omit_by_filter(sample_filter)
+        .defaults(appends)
+        .pairs()
+        .value();
+    assert_eq!(vec!((2isize, 2usize), (4isize, 4usize)), pairs);
+}
+
+#[test]
+fn test_chain_hashmap() {
+    let mut sample = HashMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    sample.insert(3isize, 3usize);
+
+    let chained = chain(sample)
+        .pick(vec!(1isize, 2))
+        .pick_by_filter(sample_filter)
+        .invert()
+        .value();
+
+    let mut expected = HashMap::new();
+    expected.insert(1usize, 1isize);
+    assert_eq!(expected, chained);
+}
+
 #[test]
 fn test_hashmap_invert() {
     let mut sample = HashMap::new();
@@ -76,6 +743,34 @@ fn test_hashmap_invert() {
     }
 }
 
+#[test]
+fn test_hashmap_invert_multi() {
+    let mut sample = HashMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 1usize);
+    sample.insert(3isize, 3usize);
+    let mut inverted = sample.invert_multi();
+    inverted.get_mut(&1usize).unwrap().sort();
+
+    assert_eq!(2usize, inverted.len());
+    assert_eq!(vec!(1isize, 2), inverted[&1usize]);
+    assert_eq!(vec!(3isize), inverted[&3usize]);
+}
+
+#[test]
+fn test_hashmap_try_invert() {
+    let mut sample = HashMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let inverted = sample.clone().try_invert().unwrap();
+    assert_eq!(sample, inverted.invert());
+
+    sample.insert(3isize, 1usize);
+    let mut error = sample.try_invert().unwrap_err();
+    error.collisions[0].1.sort();
+    assert_eq!(vec!((1usize, vec!(1isize, 3))), error.collisions);
+}
+
 #[test]
 fn test_hashmap_pick() {
     let mut sample = HashMap::new();
@@ -147,85 +842,382 @@ fn test_hashmap_defaults() {
     }
 }
 
+#[derive(Debug, PartialEq)]
+struct NoClone(f64);
+
 #[test]
-fn test_treemap_invert() {
-    let mut sample = BTreeMap::new();
-    sample.insert(1isize, 1usize);
-    sample.insert(2isize, 2usize);
-    let inverted = sample.invert();
+fn test_hashmap_non_hashable_values() {
+    let mut sample = HashMap::new();
+    sample.insert("a".to_string(), vec!(1u8));
+    sample.insert("b".to_string(), vec!(2u8));
+    sample.insert("c".to_string(), vec!(3u8));
 
-    for x in vec!(1usize, 2).iter() {
-        assert!(inverted.contains_key(x));
-        assert_eq!(*x as isize, *inverted.get(x).unwrap());
-    }
+    let picked = sample.pick(vec!("a".to_string(), "b".to_string()));
+    assert_eq!(2usize, picked.len());
+    let omitted = picked.omit(vec!("a".to_string()));
+    assert_eq!(vec!(2u8), omitted["b"]);
+
+    let mut floats = HashMap::new();
+    floats.insert(1isize, NoClone(1.5));
+    floats.insert(2isize, NoClone(2.5));
+    let mut appends = HashMap::new();
+    appends.insert(3isize, NoClone(3.5));
+
+    let filtered = floats
+        .defaults(appends)
+        .pick_by_filter(|_, v| v.0 > 2.0)
+        .omit_by_filter(|&k, _| k == 3);
+    assert_eq!(1usize, filtered.len());
+    assert_eq!(NoClone(2.5), filtered[&2isize]);
 }
 
 #[test]
-fn test_treemap_pick() {
+fn test_treemap_non_ord_values() {
     let mut sample = BTreeMap::new();
-    sample.insert(1isize, 1usize);
-    sample.insert(2isize, 2usize);
-    let v = vec!(1isize);
-    let picked = sample.pick(&v);
+    sample.insert(1isize, NoClone(1.5));
+    sample.insert(2isize, NoClone(2.5));
+    sample.insert(3isize, NoClone(3.5));
 
-    assert_eq!(1usize, picked.len());
-    assert_eq!(1usize, *picked.get(&1isize).unwrap());
+    let picked = sample.pick(&vec!(1isize, 2, 4));
+    assert_eq!(vec!(&1isize, &2), picked.keys().collect::<Vec<_>>());
+
+    let omitted = picked.omit(&vec!(1isize));
+    assert_eq!(vec!((&2isize, &NoClone(2.5))), omitted.pairs());
+
+    let mut appends = BTreeMap::new();
+    appends.insert(4isize, NoClone(4.5));
+    let defaults = omitted
+        .defaults(appends)
+        .pick_by_filter(|_, v| v.0 > 2.0)
+        .omit_by_filter(|&k, _| k == 2);
+    assert_eq!(vec!((&4isize, &NoClone(4.5))), defaults.pairs());
 }
 
-#[test]
-fn test_treemap_pick_by_filter() {
-    let mut sample = BTreeMap::new();
-    sample.insert(1isize, 1usize);
-    sample.insert(2isize, 2usize);
-    let picked = sample.pick_by_filter(sample_filter);
+// FNV-1a hasher with a tag, to check that the returned maps keep the caller's BuildHasher.
+struct FnvHasher(u64);
 
-    assert_eq!(1usize, picked.len());
-    assert_eq!(1usize, *picked.get(&1isize).unwrap());
+impl std::hash::Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            self.0 = (self.0 ^ (*byte as u64)).wrapping_mul(0x100000001b3);
+        }
+    }
 }
 
-#[test]
-fn test_treemap_omit() {
-    let mut sample = BTreeMap::new();
-    sample.insert(1isize, 1usize);
-    sample.insert(2isize, 2usize);
-    let omitted = sample.omit(&vec!(1isize));
+#[derive(Clone, Debug, PartialEq)]
+struct TaggedState(usize);
 
-    assert_eq!(1usize, omitted.len());
-    assert_eq!(2usize, *omitted.get(&2isize).unwrap());
+impl std::hash::BuildHasher for TaggedState {
+    type Hasher = FnvHasher;
+
+    fn build_hasher(&self) -> FnvHasher {
+        FnvHasher(0xcbf29ce484222325)
+    }
 }
 
-#[test]
-fn test_treemap_omit_by_filter() {
-    let mut sample = BTreeMap::new();
+fn tagged_sample() -> HashMap<isize, usize, TaggedState> {
+    let mut sample = HashMap::with_hasher(TaggedState(7));
     sample.insert(1isize, 1usize);
     sample.insert(2isize, 2usize);
-    let omitted = sample.omit_by_filter(sample_filter);
+    sample.insert(3isize, 3usize);
+    sample
+}
 
-    assert_eq!(1usize, omitted.len());
-    assert_eq!(2usize, *omitted.get(&2isize).unwrap());
+#[test]
+fn test_hashmap_custom_hasher() {
+    let inverted = tagged_sample().invert();
+    assert_eq!(&TaggedState(7), inverted.hasher());
+    assert_eq!(2isize, inverted[&2usize]);
+
+    let picked = tagged_sample().pick(vec!(1isize, 2));
+    assert_eq!(&TaggedState(7), picked.hasher());
+    assert_eq!(2usize, picked.len());
+
+    let picked = tagged_sample().pick_by_filter(sample_filter);
+    assert_eq!(&TaggedState(7), picked.hasher());
+    assert_eq!(1usize, picked.len());
+
+    let omitted = tagged_sample().omit(vec!(1isize));
+    assert_eq!(&TaggedState(7), omitted.hasher());
+    assert_eq!(2usize, omitted.len());
+
+    let omitted = tagged_sample().omit_by_filter(sample_filter);
+    assert_eq!(&TaggedState(7), omitted.hasher());
+    assert_eq!(2usize, omitted.len());
+
+    let mut appends = HashMap::with_hasher(TaggedState(8));
+    appends.insert(4isize, 4usize);
+    let defaults = tagged_sample().defaults(appends);
+    assert_eq!(&TaggedState(7), defaults.hasher());
+    assert_eq!(4usize, defaults[&4isize]);
+
+    let chained = chain(tagged_sample()).omit(vec!(3isize)).invert().value();
+    assert_eq!(&TaggedState(7), chained.hasher());
+    assert_eq!(2usize, chained.len());
 }
 
 #[test]
-fn test_treemap_defaults() {
-    let mut origin = BTreeMap::new();
+fn test_hashmap_assign() {
+    let mut origin = HashMap::new();
     origin.insert(1isize, 1usize);
     origin.insert(2isize, 2usize);
 
-    let mut appends = BTreeMap::new();
-    appends.insert(1isize, 10000usize);
-    appends.insert(3isize, 3usize);
+    let mut other = HashMap::new();
+    other.insert(1isize, 10usize);
+    other.insert(3isize, 3usize);
 
-    let defaults = origin.defaults(appends);
-    for x in vec!(1isize, 2, 3).iter() {
-        assert!(defaults.contains_key(x));
-        assert_eq!(*x as usize, *defaults.get(x).unwrap());
-    }
+    let assigned = origin.assign(other);
+    assert_eq!(3usize, assigned.len());
+    assert_eq!(10usize, assigned[&1isize]);
+    assert_eq!(2usize, assigned[&2isize]);
+    assert_eq!(3usize, assigned[&3isize]);
 }
 
 #[test]
-fn test_treemap_pairs() {
-    let mut sample = BTreeMap::new();
-    sample.insert(1isize, 1usize);
+fn test_hashmap_defaults_many() {
+    let mut origin = HashMap::new();
+    origin.insert(1isize, 1usize);
+
+    let mut first = HashMap::new();
+    first.insert(1isize, 10usize);
+    first.insert(2isize, 2usize);
+    let mut second = HashMap::new();
+    second.insert(2isize, 20usize);
+    second.insert(3isize, 3usize);
+
+    let defaults = origin.defaults_many(vec!(first, second));
+    assert_eq!(3usize, defaults.len());
+    for x in vec!(1isize, 2, 3).iter() {
+        assert_eq!(*x as usize, defaults[x]);
+    }
+    assert!(HashMap::<isize, usize>::new().defaults_many(vec!()).is_empty());
+}
+
+#[test]
+fn test_hashmap_merge_with() {
+    let mut origin = HashMap::new();
+    origin.insert(1isize, 1usize);
+    origin.insert(2isize, 2usize);
+
+    let mut other = HashMap::new();
+    other.insert(2isize, 20usize);
+    other.insert(3isize, 3usize);
+
+    let merged = origin.merge_with(other, |&k, a, b| k as usize * 100 + a + b);
+    assert_eq!(1usize, merged[&1isize]);
+    assert_eq!(222usize, merged[&2isize]);
+    assert_eq!(3usize, merged[&3isize]);
+}
+
+#[test]
+fn test_hashmap_deep_merge() {
+    let mut origin: HashMap<&str, HashMap<&str, Option<usize>>> = HashMap::new();
+    origin.insert("db", HashMap::new());
+    origin.get_mut("db").unwrap().insert("port", Some(5432));
+    origin.get_mut("db").unwrap().insert("pool", Some(4));
+    origin.insert("cache", HashMap::new());
+
+    let mut other = HashMap::new();
+    other.insert("db", HashMap::new());
+    other.get_mut("db").unwrap().insert("pool", Some(16));
+    other.get_mut("db").unwrap().insert("port", None);
+    other.get_mut("db").unwrap().insert("timeout", Some(30));
+
+    let merged = origin.deep_merge(other);
+    assert_eq!(2usize, merged.len());
+    assert_eq!(Some(5432usize), merged["db"]["port"]);
+    assert_eq!(Some(16usize), merged["db"]["pool"]);
+    assert_eq!(Some(30usize), merged["db"]["timeout"]);
+    assert!(merged["cache"].is_empty());
+}
+
+#[test]
+fn test_treemap_invert() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let inverted = sample.invert();
+
+    for x in vec!(1usize, 2).iter() {
+        assert!(inverted.contains_key(x));
+        assert_eq!(*x as isize, *inverted.get(x).unwrap());
+    }
+}
+
+#[test]
+fn test_treemap_invert_multi() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 1usize);
+    sample.insert(3isize, 3usize);
+    let inverted = sample.invert_multi();
+
+    assert_eq!(2usize, inverted.len());
+    assert_eq!(vec!(1isize, 2), inverted[&1usize]);
+    assert_eq!(vec!(3isize), inverted[&3usize]);
+}
+
+#[test]
+fn test_treemap_try_invert() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let inverted = sample.clone().try_invert().unwrap();
+    assert_eq!(sample, inverted.invert());
+
+    sample.insert(3isize, 1usize);
+    sample.insert(4isize, 2usize);
+    sample.insert(5isize, 5usize);
+    let error = sample.try_invert().unwrap_err();
+    assert_eq!(vec!((1usize, vec!(1isize, 3)), (2usize, vec!(2isize, 4))), error.collisions);
+    assert_eq!("map is not invertible, keys collide on values: 1 => [1, 3] 2 => [2, 4]", error.to_string());
+}
+
+#[test]
+fn test_treemap_pick() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let v = vec!(1isize);
+    let picked = sample.pick(&v);
+
+    assert_eq!(1usize, picked.len());
+    assert_eq!(1usize, *picked.get(&1isize).unwrap());
+}
+
+#[test]
+fn test_treemap_pick_by_filter() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let picked = sample.pick_by_filter(sample_filter);
+
+    assert_eq!(1usize, picked.len());
+    assert_eq!(1usize, *picked.get(&1isize).unwrap());
+}
+
+#[test]
+fn test_treemap_omit() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let omitted = sample.omit(&vec!(1isize));
+
+    assert_eq!(1usize, omitted.len());
+    assert_eq!(2usize, *omitted.get(&2isize).unwrap());
+}
+
+#[test]
+fn test_treemap_omit_by_filter() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let omitted = sample.omit_by_filter(sample_filter);
+
+    assert_eq!(1usize, omitted.len());
+    assert_eq!(2usize, *omitted.get(&2isize).unwrap());
+}
+
+#[test]
+fn test_treemap_defaults() {
+    let mut origin = BTreeMap::new();
+    origin.insert(1isize, 1usize);
+    origin.insert(2isize, 2usize);
+
+    let mut appends = BTreeMap::new();
+    appends.insert(1isize, 10000usize);
+    appends.insert(3isize, 3usize);
+
+    let defaults = origin.defaults(appends);
+    for x in vec!(1isize, 2, 3).iter() {
+        assert!(defaults.contains_key(x));
+        assert_eq!(*x as usize, *defaults.get(x).unwrap());
+    }
+}
+
+#[test]
+fn test_treemap_assign() {
+    let mut origin = BTreeMap::new();
+    origin.insert(1isize, 1usize);
+    origin.insert(2isize, 2usize);
+
+    let mut other = BTreeMap::new();
+    other.insert(1isize, 10usize);
+    other.insert(3isize, 3usize);
+
+    let assigned = origin.assign(other);
+    assert_eq!(3usize, assigned.len());
+    assert_eq!(10usize, assigned[&1isize]);
+    assert_eq!(2usize, assigned[&2isize]);
+    assert_eq!(3usize, assigned[&3isize]);
+}
+
+#[test]
+fn test_treemap_defaults_many() {
+    let mut origin = BTreeMap::new();
+    origin.insert(1isize, 1usize);
+
+    let mut first = BTreeMap::new();
+    first.insert(1isize, 10usize);
+    first.insert(2isize, 2usize);
+    let mut second = BTreeMap::new();
+    second.insert(2isize, 20usize);
+    second.insert(3isize, 3usize);
+
+    let defaults = origin.defaults_many(vec!(first, second));
+    assert_eq!(3usize, defaults.len());
+    for x in vec!(1isize, 2, 3).iter() {
+        assert_eq!(*x as usize, defaults[x]);
+    }
+    assert!(BTreeMap::<isize, usize>::new().defaults_many(vec!()).is_empty());
+}
+
+#[test]
+fn test_treemap_merge_with() {
+    let mut origin = BTreeMap::new();
+    origin.insert(1isize, 1usize);
+    origin.insert(2isize, 2usize);
+
+    let mut other = BTreeMap::new();
+    other.insert(2isize, 20usize);
+    other.insert(3isize, 3usize);
+
+    let merged = origin.merge_with(other, |&k, a, b| k as usize * 100 + a + b);
+    assert_eq!(1usize, merged[&1isize]);
+    assert_eq!(222usize, merged[&2isize]);
+    assert_eq!(3usize, merged[&3isize]);
+}
+
+#[test]
+fn test_treemap_deep_merge() {
+    let mut origin: BTreeMap<&str, BTreeMap<&str, Option<usize>>> = BTreeMap::new();
+    origin.insert("db", BTreeMap::new());
+    origin.get_mut("db").unwrap().insert("port", Some(5432));
+    origin.get_mut("db").unwrap().insert("pool", Some(4));
+    origin.insert("cache", BTreeMap::new());
+
+    let mut other = BTreeMap::new();
+    other.insert("db", BTreeMap::new());
+    other.get_mut("db").unwrap().insert("pool", Some(16));
+    other.get_mut("db").unwrap().insert("port", None);
+    other.get_mut("db").unwrap().insert("timeout", Some(30));
+
+    let merged = origin.deep_merge(other);
+    assert_eq!(2usize, merged.len());
+    assert_eq!(Some(5432usize), merged["db"]["port"]);
+    assert_eq!(Some(16usize), merged["db"]["pool"]);
+    assert_eq!(Some(30usize), merged["db"]["timeout"]);
+    assert!(merged["cache"].is_empty());
+}
+
+#[test]
+fn test_treemap_pairs() {
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
     sample.insert(2isize, 2usize);
     sample.insert(3isize, 3usize);
     let pairs = sample.pairs();
@@ -234,3 +1226,771 @@ fn test_treemap_pairs() {
     assert_eq!((&2isize, &2usize), pairs[1]);
     assert_eq!((&3isize, &3usize), pairs[2]);
 }
+
+#[test]
+fn test_hashset_intersection() {
+    let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: HashSet<isize> = vec!(2isize, 3).into_iter().collect();
+    assert_eq!(expected, sample.intersection_set(&other));
+}
+
+#[test]
+fn test_hashset_difference() {
+    let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: HashSet<isize> = vec!(1isize).into_iter().collect();
+    assert_eq!(expected, sample.difference_set(&other));
+}
+
+#[test]
+fn test_hashset_union() {
+    let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: HashSet<isize> = vec!(1isize, 2, 3, 4).into_iter().collect();
+    assert_eq!(expected, sample.union_set(&other));
+}
+
+#[test]
+fn test_hashset_symmetric_difference() {
+    let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: HashSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: HashSet<isize> = vec!(1isize, 4).into_iter().collect();
+    assert_eq!(expected, sample.symmetric_difference_set(&other));
+}
+
+#[test]
+fn test_hashset_partition() {
+    let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let (odd, even) = sample.partition(|&v| v % 2 == 1);
+    assert_eq!(vec!(1isize, 3).into_iter().collect::<HashSet<isize>>(), odd);
+    assert_eq!(vec!(2isize).into_iter().collect::<HashSet<isize>>(), even);
+}
+
+#[test]
+fn test_hashset_pick_and_omit_by_filter() {
+    let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let picked = sample.clone().pick_by_filter(|&v| v > 1);
+    let omitted = sample.omit_by_filter(|&v| v > 1);
+    assert_eq!(vec!(2isize, 3).into_iter().collect::<HashSet<isize>>(), picked);
+    assert_eq!(vec!(1isize).into_iter().collect::<HashSet<isize>>(), omitted);
+}
+
+#[test]
+fn test_hashset_object() {
+    let sample: HashSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let obj = sample.object(|&v| v as usize * 10);
+
+    let mut expected = HashMap::new();
+    expected.insert(1isize, 10usize);
+    expected.insert(2isize, 20usize);
+    expected.insert(3isize, 30usize);
+    assert_eq!(expected, obj);
+}
+
+#[test]
+fn test_hashset_keeps_hasher() {
+    let mut sample = HashSet::with_hasher(TaggedState(7));
+    sample.extend(vec!(1isize, 2, 3));
+    let mut other = HashSet::with_hasher(TaggedState(8));
+    other.extend(vec!(2isize, 4));
+
+    assert_eq!(&TaggedState(7), sample.clone().intersection_set(&other).hasher());
+    assert_eq!(&TaggedState(7), sample.clone().union_set(&other).hasher());
+    assert_eq!(&TaggedState(7), sample.clone().pick_by_filter(|&v| v > 1).hasher());
+    let obj = sample.object(|&v| v * 10);
+    assert_eq!(&TaggedState(7), obj.hasher());
+    assert_eq!(20isize, obj[&2isize]);
+}
+
+#[test]
+fn test_set_std_methods_not_hidden() {
+    let a: HashSet<isize> = vec!(1isize, 2).into_iter().collect();
+    let b: HashSet<isize> = vec!(2isize, 3).into_iter().collect();
+    let union: HashSet<isize> = a.union(&b).cloned().collect();
+    assert_eq!(3usize, union.len());
+    assert_eq!(vec!(&2isize), a.intersection(&b).collect::<Vec<_>>());
+
+    let a: BTreeSet<isize> = vec!(1isize, 2).into_iter().collect();
+    let b: BTreeSet<isize> = vec!(2isize, 3).into_iter().collect();
+    assert_eq!(vec!(1isize, 2, 3), a.union(&b).cloned().collect::<Vec<_>>());
+    assert_eq!(vec!(&1isize, &3), a.symmetric_difference(&b).collect::<Vec<_>>());
+    assert_eq!(vec!(&1isize), a.difference(&b).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_btreeset_intersection() {
+    let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: BTreeSet<isize> = vec!(2isize, 3).into_iter().collect();
+    assert_eq!(expected, sample.intersection_set(&other));
+}
+
+#[test]
+fn test_btreeset_difference() {
+    let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: BTreeSet<isize> = vec!(1isize).into_iter().collect();
+    assert_eq!(expected, sample.difference_set(&other));
+}
+
+#[test]
+fn test_btreeset_union() {
+    let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: BTreeSet<isize> = vec!(1isize, 2, 3, 4).into_iter().collect();
+    assert_eq!(expected, sample.union_set(&other));
+}
+
+#[test]
+fn test_btreeset_symmetric_difference() {
+    let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let other: BTreeSet<isize> = vec!(2isize, 3, 4).into_iter().collect();
+    let expected: BTreeSet<isize> = vec!(1isize, 4).into_iter().collect();
+    assert_eq!(expected, sample.symmetric_difference_set(&other));
+}
+
+#[test]
+fn test_btreeset_partition() {
+    let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let (odd, even) = sample.partition(|&v| v % 2 == 1);
+    assert_eq!(vec!(1isize, 3).into_iter().collect::<BTreeSet<isize>>(), odd);
+    assert_eq!(vec!(2isize).into_iter().collect::<BTreeSet<isize>>(), even);
+}
+
+#[test]
+fn test_btreeset_pick_and_omit_by_filter() {
+    let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let picked = sample.clone().pick_by_filter(|&v| v > 1);
+    let omitted = sample.omit_by_filter(|&v| v > 1);
+    assert_eq!(vec!(2isize, 3).into_iter().collect::<BTreeSet<isize>>(), picked);
+    assert_eq!(vec!(1isize).into_iter().collect::<BTreeSet<isize>>(), omitted);
+}
+
+#[test]
+fn test_btreeset_object() {
+    let sample: BTreeSet<isize> = vec!(1isize, 2, 3).into_iter().collect();
+    let obj = sample.object(|&v| v as usize * 10);
+
+    let mut expected = BTreeMap::new();
+    expected.insert(1isize, 10usize);
+    expected.insert(2isize, 20usize);
+    expected.insert(3isize, 30usize);
+    assert_eq!(expected, obj);
+}
+
+#[test]
+fn test_function_once() {
+    let mut count = 0usize;
+    {
+        let mut init = once(|| { count += 1; count });
+        assert_eq!(1usize, init());
+        assert_eq!(1usize, init());
+    }
+    assert_eq!(1usize, count);
+}
+
+#[test]
+fn test_function_once_poisoned() {
+    let mut init = once(|| -> usize { panic!("failed") });
+    assert!(panic::catch_unwind(AssertUnwindSafe(&mut init)).is_err());
+    assert!(panic::catch_unwind(AssertUnwindSafe(&mut init)).is_err());
+}
+
+#[test]
+fn test_function_after() {
+    let mut done = after(3, || "done");
+    assert_eq!(None, done());
+    assert_eq!(None, done());
+    assert_eq!(Some("done"), done());
+    assert_eq!(Some("done"), done());
+
+    let mut immediately = after(0, || 1isize);
+    assert_eq!(Some(1isize), immediately());
+}
+
+#[test]
+fn test_function_before() {
+    let mut count = 0usize;
+    {
+        let mut increment = before(3, || { count += 1; count });
+        assert_eq!(Some(1usize), increment());
+        assert_eq!(Some(2usize), increment());
+        assert_eq!(Some(2usize), increment());
+    }
+    assert_eq!(2usize, count);
+
+    let mut never = before(1, || 1isize);
+    assert_eq!(None, never());
+}
+
+#[test]
+fn test_function_negate() {
+    let is_odd = |v: &isize| v % 2 == 1;
+    assert_eq!(vec!(1isize, 3), vec!(1isize, 2, 3).reject(negate(is_odd)));
+
+    let mut sample = BTreeMap::new();
+    sample.insert(1isize, 1usize);
+    sample.insert(2isize, 2usize);
+    let omitted = sample.clone().omit_by_filter(negate2(sample_filter));
+    assert_eq!(1usize, omitted.len());
+    assert_eq!(1usize, omitted[&1isize]);
+
+    let picked = sample.pick_by_filter(negate2(sample_filter));
+    assert_eq!(1usize, picked.len());
+    assert_eq!(2usize, picked[&2isize]);
+}
+
+#[test]
+fn test_function_compose() {
+    let double = |v: isize| v * 2;
+    let increment = |v: isize| v + 1;
+    assert_eq!(8isize, compose(double, increment)(3));
+    assert_eq!(7isize, compose(increment, double)(3));
+}
+
+#[test]
+fn test_function_partial() {
+    let concat2 = partial2(|a: &str, b: &str| format!("{}{}", a, b), "a");
+    assert_eq!("ab", concat2("b"));
+
+    let concat3 = partial3(|a: &str, b: &str, c: &str| format!("{}{}{}", a, b, c), "a");
+    assert_eq!("abc", concat3("b", "c"));
+
+    let concat4 = partial4(|a: &str, b: &str, c: &str, d: &str| format!("{}{}{}{}", a, b, c, d), "a");
+    assert_eq!("abcd", concat4("b", "c", "d"));
+}
+
+#[test]
+fn test_function_memoize() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0usize);
+    let mut square = memoize(|v: usize| { calls.set(calls.get() + 1); v * v });
+    assert_eq!(4usize, square(2));
+    assert_eq!(4usize, square(2));
+    assert_eq!(9usize, square(3));
+    assert_eq!(2usize, calls.get());
+}
+
+#[test]
+fn test_function_memoize_with() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0usize);
+    let mut length = memoize_with(
+        |s: String| { calls.set(calls.get() + 1); s.len() },
+        |s: &String| s.to_lowercase(),
+        BTreeMap::new());
+    assert_eq!(5usize, length("Hello".to_string()));
+    assert_eq!(5usize, length("hello".to_string()));
+    assert_eq!(1usize, calls.get());
+}
+
+#[test]
+fn test_function_throttle() {
+    let clock = ManualClock::new();
+    let wait = Duration::from_millis(100);
+    let mut throttled = throttle_with_clock(|v: isize| v, wait, clock.clone());
+
+    assert_eq!(Some(1isize), throttled.call(1));
+    clock.advance(Duration::from_millis(50));
+    assert_eq!(None, throttled.call(2));
+    assert_eq!(None, throttled.call(3));
+    assert_eq!(None, throttled.poll());
+
+    clock.advance(Duration::from_millis(50));
+    assert_eq!(Some(3isize), throttled.poll());
+    assert_eq!(None, throttled.poll());
+    assert_eq!(None, throttled.call(4));
+
+    clock.advance(wait);
+    throttled.cancel();
+    assert_eq!(None, throttled.poll());
+    assert_eq!(Some(5isize), throttled.call(5));
+}
+
+#[test]
+fn test_function_throttle_system_clock() {
+    let mut throttled = throttle(|v: isize| v, Duration::from_secs(3600));
+    assert_eq!(Some(1isize), throttled.call(1));
+    assert_eq!(None, throttled.call(2));
+}
+
+#[test]
+fn test_function_debounce_trailing() {
+    let clock = ManualClock::new();
+    let wait = Duration::from_millis(100);
+    let mut debounced = debounce_with_clock(|v: isize| v, wait, Edge::Trailing, clock.clone());
+
+    assert_eq!(None, debounced.call(1));
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(None, debounced.call(2));
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(None, debounced.poll());
+
+    clock.advance(Duration::from_millis(40));
+    assert_eq!(Some(2isize), debounced.poll());
+    assert_eq!(None, debounced.poll());
+}
+
+#[test]
+fn test_function_debounce_leading() {
+    let clock = ManualClock::new();
+    let wait = Duration::from_millis(100);
+    let mut debounced = debounce_with_clock(|v: isize| v, wait, Edge::Leading, clock.clone());
+
+    assert_eq!(Some(1isize), debounced.call(1));
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(None, debounced.call(2));
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(None, debounced.call(3));
+    clock.advance(wait);
+    assert_eq!(None, debounced.poll());
+    assert_eq!(Some(4isize), debounced.call(4));
+}
+
+#[test]
+fn test_function_debounce_both() {
+    let clock = ManualClock::new();
+    let wait = Duration::from_millis(100);
+    let mut debounced = debounce_with_clock(|v: isize| v, wait, Edge::Both, clock.clone());
+
+    assert_eq!(Some(1isize), debounced.call(1));
+    clock.advance(wait);
+    assert_eq!(None, debounced.poll());
+
+    assert_eq!(Some(2isize), debounced.call(2));
+    assert_eq!(None, debounced.call(3));
+    clock.advance(wait);
+    assert_eq!(Some(3isize), debounced.poll());
+}
+
+#[test]
+fn test_function_debounce_runs_overdue_trailing_call() {
+    let clock = ManualClock::new();
+    let wait = Duration::from_millis(100);
+
+    let mut debounced = debounce_with_clock(|v: isize| v, wait, Edge::Trailing, clock.clone());
+    assert_eq!(None, debounced.call(1));
+    clock.advance(Duration::from_millis(150));
+    assert_eq!(Some(1isize), debounced.call(2));
+    clock.advance(wait);
+    assert_eq!(Some(2isize), debounced.poll());
+
+    let mut calls = Vec::new();
+    {
+        let mut debounced = debounce_with_clock(|v: isize| calls.push(v), wait, Edge::Both, clock.clone());
+        debounced.call(1);
+        debounced.call(2);
+        clock.advance(Duration::from_millis(150));
+        debounced.call(3);
+        debounced.call(4);
+        clock.advance(wait);
+        debounced.poll();
+    }
+    assert_eq!(vec!(1isize, 2, 3, 4), calls);
+}
+
+#[test]
+fn test_merge_mergeable() {
+    assert_eq!(2isize, 1isize.merge(2));
+    assert_eq!("b".to_string(), "a".to_string().merge("b".to_string()));
+    assert_eq!(vec!(3isize), vec!(1isize, 2).merge(vec!(3)));
+    assert_eq!(Some(1isize), Some(1isize).merge(None));
+    assert_eq!(Some(2isize), None.merge(Some(2isize)));
+}
+
+fn sample_value() -> Value {
+    let mut value = Value::Null;
+    value.set_path("a.b[0]", Value::from(1i64)).unwrap();
+    value.set_path("a.b[1]", Value::from(2i64)).unwrap();
+    value.set_path("a.b[2].c", Value::from("deep")).unwrap();
+    value.set_path("d", Value::from(true)).unwrap();
+    value
+}
+
+#[test]
+fn test_value_get_path() {
+    let value = sample_value();
+    assert_eq!(Some(&Value::from(2i64)), value.get_path("a.b[1]"));
+    assert_eq!(Some(&Value::from("deep")), value.get_path("a.b[2].c"));
+    assert_eq!(Some(&Value::from(true)), value.get_path("d"));
+    assert_eq!(Some(&value), value.get_path(""));
+    assert_eq!(None, value.get_path("a.b[3]"));
+    assert_eq!(None, value.get_path("a.x"));
+    assert_eq!(None, value.get_path("d.e"));
+    assert_eq!(None, value.get_path("a.b[x]"));
+
+    assert!(value.has_path("a.b[0]"));
+    assert!(! value.has_path("a.c"));
+}
+
+#[test]
+fn test_value_set_path() {
+    let mut value = sample_value();
+    value.set_path("a.b[0]", Value::from("replaced")).unwrap();
+    assert_eq!(Some(&Value::from("replaced")), value.get_path("a.b[0]"));
+
+    *value.get_path_mut("d").unwrap() = Value::Null;
+    assert_eq!(Some(&Value::Null), value.get_path("d"));
+
+    value.set_path("d.e", Value::from(1i64)).unwrap();
+    assert_eq!(Some(&Value::from(1i64)), value.get_path("d.e"));
+
+    assert_eq!(Err(PathError::NotContainer("a.b".to_string())), value.set_path("a.b.c", Value::Null));
+    assert_eq!(Err(PathError::OutOfBounds("a.b[5]".to_string())), value.set_path("a.b[5]", Value::Null));
+    assert_eq!(Err(PathError::Syntax(2)), value.set_path("a..b", Value::Null));
+    assert_eq!(Err(PathError::Syntax(3)), value.set_path("a[1x]", Value::Null));
+    assert_eq!(Err(PathError::Syntax(2)), value.set_path("a.", Value::Null));
+}
+
+#[test]
+fn test_value_set_path_leaves_value_on_error() {
+    let mut value = Value::Null;
+    assert_eq!(Err(PathError::OutOfBounds("a.b[5]".to_string())), value.set_path("a.b[5]", Value::from(1i64)));
+    assert_eq!(Value::Null, value);
+    assert_eq!(Err(PathError::OutOfBounds("a[1]".to_string())), value.set_path("a[1].b", Value::from(1i64)));
+    assert_eq!(Value::Null, value);
+
+    let mut value = sample_value();
+    let origin = value.clone();
+    assert_eq!(Err(PathError::OutOfBounds("x.y[3]".to_string())), value.set_path("x.y[3]", Value::Null));
+    assert!(! value.has_path("x"));
+    assert_eq!(Err(PathError::NotContainer("d".to_string())), value.set_path("d.e.f", Value::Null));
+    assert_eq!(origin, value);
+    assert_eq!(Err(PathError::NotContainer("a.b[2].c".to_string())), value.set_path("a.b[2].c.new", Value::Null));
+    assert_eq!(origin, value);
+}
+
+#[test]
+fn test_value_delete_path() {
+    let mut value = sample_value();
+    assert_eq!(Some(Value::from(1i64)), value.delete_path("a.b[0]"));
+    assert_eq!(Some(&Value::from(2i64)), value.get_path("a.b[0]"));
+    assert_eq!(Some(Value::from(true)), value.delete_path("d"));
+    assert_eq!(None, value.delete_path("d"));
+    assert_eq!(None, value.delete_path("a.b[9]"));
+    assert_eq!(None, value.delete_path(""));
+    assert!(! value.has_path("d"));
+}
+
+#[test]
+fn test_value_object_with_treemap_methods() {
+    let mut value = sample_value();
+    value.set_path("e", Value::from(1i64)).unwrap();
+    let object = value.as_object().unwrap().clone();
+
+    let picked = object.clone().pick(&vec!("d".to_string(), "e".to_string()));
+    assert_eq!(2usize, picked.len());
+
+    let omitted = object.clone().omit(&vec!("a".to_string()));
+    assert_eq!(vec!("d", "e"), omitted.keys().map(|k| k.as_str()).collect::<Vec<_>>());
+
+    let mut appends = BTreeMap::new();
+    appends.insert("f".to_string(), Value::Null);
+    assert_eq!(4usize, object.clone().defaults(appends).len());
+
+    let inverted = omitted.invert();
+    assert_eq!("d".to_string(), inverted[&Value::from(true)]);
+    assert_eq!("e".to_string(), inverted[&Value::from(1i64)]);
+}
+
+#[test]
+fn test_value_ordering() {
+    assert!(Value::Null < Value::from(false));
+    assert!(Value::from(true) < Value::from(0i64));
+    assert!(Value::from(-1.5) < Value::from(1i64));
+    assert!(Value::from(1i64) < Value::from("a"));
+    assert_eq!(Value::from(f64::NAN), Value::from(f64::NAN));
+
+    assert!(Value::from(1.0) < Value::from(1i64));
+    assert!(Value::from(1i64) < Value::from(1.5));
+    assert!(Value::from(-2i64) < Value::from(-1.5));
+    assert!(Value::from(u64::MAX - 1) < Value::from(u64::MAX));
+    assert!(Value::from(u64::MAX) < Value::from(f64::INFINITY));
+    assert!(Value::from(f64::NEG_INFINITY) < Value::from(i64::MIN));
+}
+
+fn template_context() -> BTreeMap<String, Value> {
+    let mut user = Value::Null;
+    user.set_path("name", Value::from("moe")).unwrap();
+    user.set_path("admin", Value::from(false)).unwrap();
+    user.set_path("tags[0]", Value::from("<b>")).unwrap();
+    user.set_path("tags[1]", Value::from("a&b")).unwrap();
+
+    let mut context = BTreeMap::new();
+    context.insert("user".to_string(), user);
+    context.insert("count".to_string(), Value::from(3i64));
+    return context;
+}
+
+#[test]
+fn test_template_interpolate_and_escape() {
+    let context = template_context();
+    let rendered = template("<%= user.name %> has <%= count %> <%- user.tags[0] %> <%= user.tags[0] %>").unwrap().render(&context);
+    assert_eq!("moe has 3 &lt;b&gt; <b>", rendered);
+    assert_eq!("[]", template("[<%= missing.path %>]").unwrap().render(&context));
+    assert_eq!("<b>,a&b", template("<%=user.tags%>").unwrap().render(&context));
+
+    let mut hashmap = HashMap::new();
+    hashmap.insert("name".to_string(), "'curly'");
+    assert_eq!("&#x27;curly&#x27;", template("<%- name %>").unwrap().render(&hashmap));
+}
+
+#[test]
+fn test_template_blocks() {
+    let context = template_context();
+    let tags = template("<% each user.tags as tag %>[<%- tag %>]<% end %>").unwrap();
+    assert_eq!("[&lt;b&gt;][a&amp;b]", tags.render(&context));
+
+    let admin = template("<% if user.admin %>admin<% else %><% if user.name %>user <%= user.name %><% end %><% end %>").unwrap();
+    assert_eq!("user moe", admin.render(&context));
+    assert_eq!("", admin.render(&BTreeMap::<String, Value>::new()));
+
+    let nested = template("<% each user.tags as tag %><% each user.tags as other %><%= tag %><%= other %>;<% end %><% end %>").unwrap();
+    assert_eq!("<b><b>;<b>a&b;a&b<b>;a&ba&b;", nested.render(&context));
+
+    let value = Value::from(template_context());
+    assert_eq!("moe", template("<%= user.name %>").unwrap().render(&value));
+}
+
+#[test]
+fn test_template_delimiters() {
+    let context = template_context();
+    let braces = Template::compile_with_delimiters("{{= user.name }}{{ if count }}!{{ end }}<%= count %>", "{{", "}}").unwrap();
+    assert_eq!("moe!<%= count %>", braces.render(&context));
+
+    let error = Template::compile_with_delimiters("", "", "}}").err().unwrap();
+    assert_eq!(TemplateErrorKind::EmptyDelimiter, error.kind);
+}
+
+#[test]
+fn test_template_errors() {
+    let error = template("line 1\n  <%= name").err().unwrap();
+    assert_eq!(TemplateError { kind: TemplateErrorKind::UnclosedTag, line: 2, column: 3 }, error);
+    assert_eq!("line 2, column 3: tag is not closed", error.to_string());
+
+    let error = template("<% if a %>\n<% each b as c %>\n<% end %>").err().unwrap();
+    assert_eq!(TemplateError { kind: TemplateErrorKind::UnclosedBlock("if a".to_string()), line: 1, column: 1 }, error);
+
+    let error = template("ok\n\nü <% end %>").err().unwrap();
+    assert_eq!(TemplateError { kind: TemplateErrorKind::UnexpectedTag("end".to_string()), line: 3, column: 3 }, error);
+
+    let error = template("<% each a as b %><% else %><% end %>").err().unwrap();
+    assert_eq!(TemplateErrorKind::UnexpectedTag("else".to_string()), error.kind);
+
+    let error = template("<% if a %><% else %><% else %><% end %>").err().unwrap();
+    assert_eq!(TemplateErrorKind::UnexpectedTag("else".to_string()), error.kind);
+
+    assert_eq!(TemplateErrorKind::InvalidTag("while a".to_string()), template("<% while a %>").err().unwrap().kind);
+    assert_eq!(TemplateErrorKind::InvalidTag("=".to_string()), template("<%= %>").err().unwrap().kind);
+    assert_eq!(TemplateErrorKind::InvalidTag("- a..b".to_string()), template("<%- a..b %>").err().unwrap().kind);
+}
+
+#[test]
+fn test_string_escape_and_unescape() {
+    let html = "<a href=\"x\">Tom & 'Jerry' `ü` 日本</a>";
+    assert_eq!("&lt;a href=&quot;x&quot;&gt;Tom &amp; &#x27;Jerry&#x27; &#x60;ü&#x60; 日本&lt;/a&gt;", html.escape());
+    assert_eq!(html, html.escape().unescape());
+    assert_eq!(html.to_string(), html.to_string().escape().unescape());
+    assert_eq!("' ' ` é 😀", "&#39; &apos; &#96; &#xE9; &#x1F600;".unescape());
+    assert_eq!("& &amp &unknown; &#xD800; 日&", "& &amp &unknown; &#xD800; 日&".unescape());
+}
+
+#[test]
+fn test_string_case() {
+    assert_eq!("Ärger", "ärger".capitalize());
+    assert_eq!("SSo", "ßo".capitalize());
+    assert_eq!("", "".capitalize());
+
+    assert_eq!("größeÄnderung", "größe-änderung".camelize());
+    assert_eq!("ÉtéChaud", " _été chaud ".camelize());
+    assert_eq!("größe_änderung", "GrößeÄnderung".underscored());
+    assert_eq!("größe_änderung", "größe-- änderung".underscored());
+    assert_eq!("x1_value", "x1Value".underscored());
+    assert_eq!("-größe-änderung", "GrößeÄnderung".dasherize());
+    assert_eq!("größe-änderung", "größe_änderung".dasherize());
+    assert_eq!("", "".dasherize());
+}
+
+#[test]
+fn test_string_truncate_words_pad() {
+    let mut inherent = "Hello".to_string();
+    inherent.truncate(2);
+    assert_eq!("He", inherent);
+    assert_eq!("日本語...", "日本語のテキスト".truncate_with(3, "..."));
+    assert_eq!("日本語", "日本語".truncate_with(3, "..."));
+    assert_eq!("…", "日本語".truncate_with(0, "…"));
+    assert_eq!("Hello", "Hello world".to_string().truncate_with(5, ""));
+
+    assert_eq!(vec!("Grüß", "dich", "日本"), "Grüß\u{3000}dich \n日本".words());
+    assert!("   ".words().is_empty());
+
+    assert_eq!("ééé日本", "日本".pad(5, 'é', Pad::Left));
+    assert_eq!("日本ééé", "日本".pad(5, 'é', Pad::Right));
+    assert_eq!("é日本éé", "日本".pad(5, 'é', Pad::Both));
+    assert_eq!("日本", "日本".pad(1, 'é', Pad::Both));
+}
+
+#[test]
+fn test_string_strip_tags() {
+    assert_eq!("日本 text", "<p class=\"ü\">日本 <b>text</b></p>".strip_tags());
+    assert_eq!("a > b", "a > b".strip_tags());
+    assert_eq!("unclosed <tag", "unclosed <tag".strip_tags());
+    assert_eq!("1 < 2 is true", "1 < 2 is true".strip_tags());
+    assert_eq!("a <> b", "a <> b".strip_tags());
+    assert_eq!("", "</>".strip_tags());
+}
+
+#[test]
+fn test_random_xorshift_is_reproducible() {
+    let mut rng = XorShift::new(42);
+    assert_eq!(vec!(3580622183945639842u64, 10378725325292465923, 8967075514996744559), vec!(rng.next_u64(), rng.next_u64(), rng.next_u64()));
+    assert!(XorShift::new(0).next_u64() != XorShift::new(1).next_u64());
+
+    let mut rng = XorShift::new(0);
+    assert_eq!(vec!(1i64, 2, 4, 3, 5), (0..5).map(|_| random(1, 6, &mut rng)).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_random_bounds() {
+    let mut rng = XorShift::new(3);
+    for _ in 0..1000 {
+        let value = random(-3, 3, &mut rng);
+        assert!((-3..=3).contains(&value));
+    }
+    assert_eq!(5i64, random(5, 5, &mut rng));
+    assert!((1..=6).contains(&random(6, 1, &mut rng)));
+    random(i64::MIN, i64::MAX, &mut rng);
+    assert_eq!(0u64, rng.below(0));
+
+    let mut counts = [0usize; 3];
+    for _ in 0..3000 {
+        if let Some(count) = counts.get_mut(rng.below(3) as usize) { *count += 1; }
+    }
+    assert!(counts.iter().all(|&count| count > 900));
+}
+
+#[test]
+fn test_vec_shuffle_and_sample() {
+    let sample: Vec<isize> = (1..11).collect();
+    assert_eq!(vec!(3isize, 6, 10, 9, 7, 4, 1, 5, 8, 2), sample.clone().shuffle_with(&mut XorShift::new(42)));
+    assert_eq!(vec!(3isize, 6, 10), sample.clone().sample(3, &mut XorShift::new(42)));
+
+    let mut shuffled = sample.clone().shuffle_with(&mut XorShift::new(9));
+    shuffled.sort();
+    assert_eq!(sample, shuffled);
+
+    let sampled = sample.clone().sample(5, &mut XorShift::new(9));
+    assert_eq!(5usize, sampled.clone().uniq().len());
+    assert!(sampled.iter().all(|v| sample.contains(v)));
+    assert_eq!(10usize, sample.clone().sample(20, &mut XorShift::new(9)).len());
+    assert!(Vec::<isize>::new().sample(1, &mut XorShift::new(9)).is_empty());
+
+    assert!(sample.contains(sample.sample_one(&mut XorShift::new(9)).unwrap()));
+    assert_eq!(None, Vec::<isize>::new().sample_one(&mut XorShift::new(9)));
+}
+
+fn leaderboard() -> BTreeMap<&'static str, usize> {
+    let mut scores = BTreeMap::new();
+    scores.insert("moe", 40usize);
+    scores.insert("larry", 50usize);
+    scores.insert("curly", 10usize);
+    scores.insert("shemp", 50usize);
+    return scores;
+}
+
+#[test]
+fn test_treemap_max_and_min() {
+    let scores = leaderboard();
+    assert_eq!(Some((&"larry", &50usize)), scores.max_by_value());
+    assert_eq!(Some((&"curly", &10usize)), scores.min_by_value());
+    assert_eq!(Some((&"curly", &10usize)), scores.max_entry_by(|name, _| name.len()));
+    assert_eq!(Some((&"curly", &10usize)), scores.max_entry_by(|_, &score| score % 20));
+
+    let empty: BTreeMap<isize, isize> = BTreeMap::new();
+    assert_eq!(None, empty.max_by_value());
+    assert_eq!(None, empty.min_by_value());
+    assert_eq!(None, empty.max_entry_by(|&k, _| k));
+}
+
+#[test]
+fn test_treemap_sort_by_value_and_top_n() {
+    assert_eq!(vec!(("curly", 10usize), ("moe", 40), ("larry", 50), ("shemp", 50)), leaderboard().sort_by_value());
+    assert_eq!(vec!(("larry", 50usize), ("shemp", 50), ("moe", 40)), leaderboard().top_n(3, |_, &score| score));
+    assert_eq!(vec!(("curly", 10usize)), leaderboard().top_n(1, |_, &score| std::cmp::Reverse(score)));
+    assert_eq!(4usize, leaderboard().top_n(10, |_, &score| score).len());
+    assert_eq!(leaderboard().top_n(4, |_, &score| score), leaderboard().top_n(usize::MAX, |_, &score| score));
+    assert!(leaderboard().top_n(0, |_, &score| score).is_empty());
+}
+
+#[test]
+fn test_hashmap_max_min_sort_and_top_n() {
+    let scores: HashMap<&str, usize> = leaderboard().into_iter().collect();
+    assert_eq!(&50usize, scores.max_by_value().unwrap().1);
+    assert_eq!(Some((&"curly", &10usize)), scores.min_by_value());
+    assert_eq!(Some((&"moe", &40usize)), scores.max_entry_by(|_, &score| score % 50));
+
+    let sorted: Vec<usize> = scores.clone().sort_by_value().into_iter().map(|(_, score)| score).collect();
+    assert_eq!(vec!(10usize, 40, 50, 50), sorted);
+
+    let top = scores.clone().top_n(3, |_, &score| score);
+    assert_eq!(vec!(50usize, 50, 40), top.iter().map(|&(_, score)| score).collect::<Vec<_>>());
+    assert_eq!(vec!(("moe", 40usize)), scores.clone().top_n(1, |&name, _| name == "moe"));
+    assert_eq!(vec!(50usize, 50, 40, 10), scores.clone().top_n(10, |_, &score| score).into_iter().map(|(_, score)| score).collect::<Vec<_>>());
+    assert_eq!(vec!(50usize, 50, 40, 10), scores.top_n(usize::MAX, |_, &score| score).into_iter().map(|(_, score)| score).collect::<Vec<_>>());
+    assert_eq!(None, HashMap::<isize, isize>::new().max_by_value());
+}
+
+#[test]
+fn test_treemap_transforms() {
+    let scores = leaderboard();
+    let halved = scores.clone().map_values(|name, score| format!("{}:{}", name, score / 2));
+    assert_eq!("moe:20", halved["moe"]);
+    assert_eq!(4usize, halved.len());
+
+    let by_initial = scores.clone().map_keys(|name, _| name.chars().next()).unwrap();
+    assert_eq!(Some(&10usize), by_initial.get(&Some('c')));
+    let by_length = scores.clone().map_keys_with(|name, _| name.len(), |_, first, _| first);
+    assert_eq!(vec!((3usize, 40usize), (5, 10)), by_length.into_iter().collect::<Vec<_>>());
+    let by_length = scores.clone().map_keys_with(|name, _| name.len(), |_, _, last| last);
+    assert_eq!(vec!((3usize, 40usize), (5, 50)), by_length.into_iter().collect::<Vec<_>>());
+    assert_eq!(Err(CollisionError { key: 5usize }), scores.clone().map_keys(|name, _| name.len()));
+    assert_eq!("several keys are mapped to 5", CollisionError { key: 5usize }.to_string());
+
+    let passing = scores.clone().filter_map_values(|_, score| if score >= 40 { Some(score - 40) } else { None });
+    assert_eq!(vec!(("larry", 10usize), ("moe", 0), ("shemp", 10)), passing.into_iter().collect::<Vec<_>>());
+
+    assert_eq!(Some(&"larry"), scores.find_key(|_, &score| score == 50));
+    assert_eq!(None, scores.find_key(|_, &score| score > 50));
+
+    let (high, low) = scores.clone().partition(|_, &score| score >= 50);
+    assert_eq!(vec!("larry", "shemp"), high.keys().cloned().collect::<Vec<_>>());
+    assert_eq!(vec!("curly", "moe"), low.keys().cloned().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_hashmap_transforms() {
+    let scores: HashMap<&str, usize> = leaderboard().into_iter().collect();
+    let doubled = scores.clone().map_values(|_, score| score * 2);
+    assert_eq!(80usize, doubled["moe"]);
+
+    let upper = scores.clone().map_keys(|name, _| name.to_uppercase()).unwrap();
+    assert_eq!(10usize, upper["CURLY"]);
+    let by_score = scores.clone().map_keys_with(|_, &score| score, |_, a, b| a + b);
+    assert_eq!(100usize, by_score[&50usize]);
+    assert_eq!(3usize, by_score.len());
+    assert_eq!(Err(CollisionError { key: 50usize }), scores.clone().map_keys(|_, &score| score));
+
+    let passing = scores.clone().filter_map_values(|_, score| if score >= 40 { Some(score) } else { None });
+    assert_eq!(3usize, passing.len());
+    assert!(!passing.contains_key("curly"));
+
+    assert_eq!(Some(&"moe"), scores.find_key(|_, &score| score == 40));
+    assert_eq!(None, scores.find_key(|_, &score| score > 50));
+
+    let (high, low) = scores.partition(|_, &score| score >= 50);
+    assert_eq!(2usize, high.len());
+    assert!(low.contains_key("curly") && low.contains_key("moe"));
+}
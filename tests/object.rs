@@ -0,0 +1,122 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate underscore;
+
+use underscore::object::{pick, omit, defaults, defaults_value, to_value, from_value};
+use underscore::value::Value;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+struct User {
+    name: String,
+    age: Option<u32>,
+    #[serde(rename = "mail")]
+    email: Option<String>,
+}
+
+fn sample_user() -> User {
+    User { name: "moe".to_string(), age: None, email: Some("moe@example.com".to_string()) }
+}
+
+#[test]
+fn test_object_pick() {
+    let picked = pick(&sample_user(), &["name", "mail", "unknown"]).unwrap();
+    assert_eq!(2usize, picked.len());
+    assert_eq!(Value::from("moe"), picked["name"]);
+    assert_eq!(Value::from("moe@example.com"), picked["mail"]);
+
+    let value = Value::from(picked);
+    assert_eq!(Some(&Value::from("moe")), value.get_path("name"));
+}
+
+#[test]
+fn test_object_omit() {
+    let omitted = omit(&sample_user(), &["mail"]).unwrap();
+    assert_eq!(vec!("age", "name"), omitted.keys().map(|k| k.as_str()).collect::<Vec<_>>());
+    assert_eq!(Value::Null, omitted["age"]);
+}
+
+#[test]
+fn test_object_not_an_object() {
+    assert!(pick(&1isize, &["a"]).is_err());
+    assert!(omit(&vec!(1isize), &["a"]).is_err());
+}
+
+#[test]
+fn test_object_defaults() {
+    let fallback = User { name: "anonymous".to_string(), age: Some(20), email: Some("none".to_string()) };
+    let filled = defaults(&sample_user(), &fallback).unwrap();
+    assert_eq!(User { name: "moe".to_string(), age: Some(20), email: Some("moe@example.com".to_string()) }, filled);
+}
+
+#[test]
+fn test_object_defaults_value() {
+    let fallback = User { name: "anonymous".to_string(), age: Some(20), email: None };
+    let mut partial = Value::Null;
+    partial.set_path("name", Value::from("curly")).unwrap();
+
+    let filled: User = defaults_value(partial, &fallback).unwrap();
+    assert_eq!(User { name: "curly".to_string(), age: Some(20), email: None }, filled);
+    assert!(defaults_value(Value::from(1i64), &fallback).is_err());
+}
+
+#[test]
+fn test_object_value_round_trip() {
+    let value = to_value(&sample_user()).unwrap();
+    assert_eq!(Some(&Value::from("moe@example.com")), value.get_path("mail"));
+    assert_eq!(sample_user(), from_value::<User>(value.clone()).unwrap());
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(r#"{"age":null,"mail":"moe@example.com","name":"moe"}"#, json);
+    assert_eq!(value, serde_json::from_str::<Value>(&json).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+struct Account {
+    id: u64,
+    balance: i64,
+    owner: Option<String>,
+}
+
+#[test]
+fn test_object_defaults_large_integers() {
+    let account = Account { id: u64::MAX, balance: i64::MIN + 1, owner: None };
+    let fallback = Account { id: 9007199254740993, balance: -9007199254740993, owner: Some("moe".to_string()) };
+    let filled = defaults(&account, &fallback).unwrap();
+    assert_eq!(Account { id: u64::MAX, balance: i64::MIN + 1, owner: Some("moe".to_string()) }, filled);
+
+    let filled = defaults(&fallback, &account).unwrap();
+    assert_eq!(fallback, filled);
+
+    let mut partial = Value::Null;
+    partial.set_path("owner", Value::from("curly")).unwrap();
+    partial.set_path("id", Value::Null).unwrap();
+    let filled: Account = defaults_value(partial, &fallback).unwrap();
+    assert_eq!(Account { id: 9007199254740993, balance: -9007199254740993, owner: Some("curly".to_string()) }, filled);
+}
+
+#[test]
+fn test_object_pick_and_omit_large_integers() {
+    let account = Account { id: u64::MAX, balance: i64::MIN, owner: None };
+
+    let picked = pick(&account, &["id", "balance"]).unwrap();
+    assert_eq!(Value::from(u64::MAX), picked["id"]);
+    assert_eq!(Value::from(i64::MIN), picked["balance"]);
+
+    let omitted = omit(&account, &["balance"]).unwrap();
+    assert_eq!(Value::from(u64::MAX), omitted["id"]);
+    assert_ne!(Value::from(u64::MAX - 1), omitted["id"]);
+}
+
+#[test]
+fn test_object_value_round_trip_large_integers() {
+    let account = Account { id: u64::MAX, balance: -9007199254740993, owner: Some("moe".to_string()) };
+    let value = to_value(&account).unwrap();
+    assert_eq!(account, from_value::<Account>(value.clone()).unwrap());
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(r#"{"balance":-9007199254740993,"id":18446744073709551615,"owner":"moe"}"#, json);
+    assert_eq!(value, serde_json::from_str::<Value>(&json).unwrap());
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "parallel")]
+
+#[macro_use]
+extern crate proptest;
+extern crate underscore;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use underscore::vec::VecU;
+use underscore::hashmap::HashMapU;
+use underscore::btreemap::BTreeMapU;
+use underscore::parallel::{ParVecU, ParHashMapU, ParBTreeMapU};
+
+#[test]
+fn test_par_uniq_keeps_first_occurrence() {
+    let sample = vec!(5isize, 1, 5, 2, 1, 3, 2);
+    assert_eq!(vec!(5isize, 1, 2, 3), sample.par_uniq());
+    assert_eq!(Vec::<isize>::new(), Vec::<isize>::new().par_uniq());
+}
+
+#[test]
+fn test_par_group_by_keeps_order_in_group() {
+    let grouped = (0..1000isize).collect::<Vec<_>>().par_group_by(|&v| v % 3);
+    assert_eq!((0..1000isize).filter(|v| v % 3 == 1).collect::<Vec<_>>(), grouped[&1]);
+}
+
+proptest! {
+    #[test]
+    fn test_par_reject_matches_reject(sample in proptest::collection::vec(-50isize..50, 0..2000)) {
+        prop_assert_eq!(sample.clone().reject(|&v| v % 3 == 0), sample.par_reject(|&v| v % 3 == 0));
+    }
+
+    #[test]
+    fn test_par_without_matches_without(sample in proptest::collection::vec(-50isize..50, 0..2000), values in proptest::collection::vec(-50isize..50, 0..20)) {
+        prop_assert_eq!(sample.clone().without(&values), sample.par_without(&values));
+    }
+
+    #[test]
+    fn test_par_uniq_matches_uniq(sample in proptest::collection::vec(-50isize..50, 0..2000)) {
+        prop_assert_eq!(sample.clone().uniq(), sample.par_uniq());
+    }
+
+    #[test]
+    fn test_par_group_by_matches_group_by(sample in proptest::collection::vec(-50isize..50, 0..2000)) {
+        prop_assert_eq!(sample.clone().group_by(|&v| v % 7), sample.par_group_by(|&v| v % 7));
+    }
+
+    #[test]
+    fn test_hashmap_par_filters_match_filters(sample in proptest::collection::hash_map(-500isize..500, 0usize..100, 0..500)) {
+        let sample: HashMap<isize, usize> = sample;
+        prop_assert_eq!(sample.clone().pick_by_filter(|&k, &v| k as usize % 2 == v % 2), sample.clone().par_pick_by_filter(|&k, &v| k as usize % 2 == v % 2));
+        prop_assert_eq!(sample.clone().omit_by_filter(|&k, &v| k as usize % 2 == v % 2), sample.par_omit_by_filter(|&k, &v| k as usize % 2 == v % 2));
+    }
+
+    #[test]
+    fn test_treemap_par_filters_match_filters(sample in proptest::collection::btree_map(-500isize..500, 0usize..100, 0..500)) {
+        let sample: BTreeMap<isize, usize> = sample;
+        prop_assert_eq!(sample.clone().pick_by_filter(|&k, &v| k as usize % 2 == v % 2), sample.clone().par_pick_by_filter(|&k, &v| k as usize % 2 == v % 2));
+        prop_assert_eq!(sample.clone().omit_by_filter(|&k, &v| k as usize % 2 == v % 2), sample.par_omit_by_filter(|&k, &v| k as usize % 2 == v % 2));
+    }
+}
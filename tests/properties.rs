@@ -0,0 +1,324 @@
+#![allow(clippy::needless_return)]
+
+#[macro_use]
+extern crate proptest;
+extern crate underscore;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use proptest::collection::{vec, hash_map, btree_map};
+use underscore::vec::{VecU, LengthPolicy, DuplicatePolicy};
+use underscore::hashmap::HashMapU;
+use underscore::btreemap::BTreeMapU;
+
+// The reference model keeps the elements of sample which satisfy f, in order.
+fn model_filter(sample: &[i8], f: impl Fn(&i8) -> bool) -> Vec<i8> {
+    let mut filtered = Vec::new();
+    for element in sample.iter() {
+        if f(element) { filtered.push(*element); }
+    }
+    return filtered;
+}
+
+fn model_uniq(sample: &[i8]) -> Vec<i8> {
+    let mut uniq: Vec<i8> = Vec::new();
+    for element in sample.iter() {
+        if ! uniq.contains(element) { uniq.push(*element); }
+    }
+    return uniq;
+}
+
+fn model_object(keys: &[i8], values: &[i8]) -> BTreeMap<i8, Vec<Option<i8>>> {
+    let mut obj: BTreeMap<i8, Vec<Option<i8>>> = BTreeMap::new();
+    for (i, key) in keys.iter().enumerate() {
+        obj.entry(*key).or_default().push(values.get(i).cloned());
+    }
+    return obj;
+}
+
+fn values() -> impl proptest::strategy::Strategy<Value = Vec<i8>> {
+    vec(-8i8..8, 0..40)
+}
+
+proptest! {
+    #[test]
+    fn test_vec_without_and_intersection(sample in values(), others in values()) {
+        let without = model_filter(&sample, |v| ! others.contains(v));
+        let intersection = model_filter(&sample, |v| others.contains(v));
+
+        prop_assert_eq!(&without, &sample.clone().without(&others));
+        prop_assert_eq!(&without, &sample.clone().without_hash(&others));
+        prop_assert_eq!(&without, &sample.clone().without_ord(&others));
+        prop_assert_eq!(&without, &sample.clone().difference(&[&others]));
+        prop_assert_eq!(&intersection, &sample.clone().intersection(&others));
+        prop_assert_eq!(&intersection, &sample.clone().intersection_hash(&others));
+        prop_assert_eq!(&intersection, &sample.clone().intersection_ord(&others));
+        prop_assert_eq!(&intersection, &sample.clone().intersection_all(&[&others]));
+        prop_assert!(sample.without(&others).intersection(&others).is_empty());
+    }
+
+    #[test]
+    fn test_vec_many_others(sample in values(), a in values(), b in values()) {
+        let difference = model_filter(&sample, |v| ! a.contains(v) && ! b.contains(v));
+        let intersection = model_filter(&sample, |v| a.contains(v) && b.contains(v));
+        let mut union = sample.clone();
+        union.extend(a.iter().cloned());
+        union.extend(b.iter().cloned());
+
+        prop_assert_eq!(&difference, &sample.clone().difference(&[&a, &b]));
+        prop_assert_eq!(&intersection, &sample.clone().intersection_all(&[&a, &b]));
+        prop_assert_eq!(model_uniq(&union), sample.union(&[&a, &b]));
+    }
+
+    #[test]
+    fn test_vec_uniq(sample in values()) {
+        let uniq = model_uniq(&sample);
+        prop_assert_eq!(&uniq, &sample.clone().uniq());
+        prop_assert_eq!(&uniq, &sample.clone().uniq_hash());
+        prop_assert_eq!(&uniq, &sample.clone().uniq_ord());
+
+        let mut sorted = sample.clone();
+        sorted.sort();
+        prop_assert_eq!(model_uniq(&sorted), sorted.uniq_sorted());
+    }
+
+    #[test]
+    fn test_vec_index(sample in values(), value in -8i8..8) {
+        prop_assert_eq!(VecU::first(&sample), sample[..].first());
+        prop_assert_eq!(sample.iter().position(|v| *v == value), sample.index_of(&value));
+        prop_assert_eq!(sample.iter().rposition(|v| *v == value), sample.last_index_of(&value));
+
+        let mut sorted = sample.clone();
+        sorted.sort();
+        let lowest = sorted.iter().filter(|v| **v < value).count();
+        prop_assert_eq!(lowest, sorted.sorted_index(&value));
+        prop_assert_eq!(lowest, sorted.sorted_index_by(&value, |v| *v));
+        prop_assert_eq!(sorted.iter().position(|v| *v == value), sorted.index_of_sorted(&value));
+    }
+
+    #[test]
+    fn test_vec_object(keys in values(), values in values()) {
+        let model = model_object(&keys, &values);
+        let has_duplicates = model.values().any(|values| values.len() > 1);
+        let truncated_keys: Vec<i8> = keys.iter().take(values.len()).cloned().collect();
+        let collected: BTreeMap<i8, Vec<i8>> = model_object(&truncated_keys, &values).into_iter()
+            .map(|(key, values)| (key, values.into_iter().flatten().collect()))
+            .collect();
+
+        prop_assert_eq!(Ok(collected.clone()), keys.clone().object_collect(values.clone(), LengthPolicy::Truncate));
+        prop_assert_eq!(Ok(collected.clone().into_iter().collect::<HashMap<_, _>>()), keys.clone().object_hashmap_collect(values.clone(), LengthPolicy::Truncate));
+        prop_assert_eq!(keys.len() != values.len(), keys.clone().object_collect(values.clone(), LengthPolicy::Error).is_err());
+
+        for &duplicates in [DuplicatePolicy::First, DuplicatePolicy::Last, DuplicatePolicy::Error].iter() {
+            let pick = |values: &Vec<Option<i8>>| match duplicates {
+                DuplicatePolicy::Last => values.iter().last().cloned().flatten(),
+                _ => values.iter().next().cloned().flatten(),
+            };
+            let expected: BTreeMap<i8, Option<i8>> = model.iter().map(|(key, values)| (*key, pick(values))).collect();
+
+            let filled = keys.clone().object_fill_none(values.clone(), duplicates);
+            let hashed = keys.clone().object_hashmap_fill_none(values.clone(), duplicates);
+            if duplicates == DuplicatePolicy::Error && has_duplicates {
+                prop_assert!(filled.is_err());
+                prop_assert!(hashed.is_err());
+            } else {
+                prop_assert_eq!(Ok(expected.clone()), filled);
+                prop_assert_eq!(Ok(expected.into_iter().collect::<HashMap<_, _>>()), hashed);
+            }
+
+            let truncated = keys.clone().object_with(values.clone(), LengthPolicy::Truncate, duplicates);
+            if duplicates == DuplicatePolicy::Error && collected.values().any(|values| values.len() > 1) {
+                prop_assert!(truncated.is_err());
+            } else {
+                let expected: BTreeMap<i8, i8> = collected.iter().filter_map(|(key, values)| {
+                    let value = if duplicates == DuplicatePolicy::Last { values.last() } else { values.first() };
+                    value.map(|value| (*key, *value))
+                }).collect();
+                prop_assert_eq!(Ok(expected), truncated);
+            }
+
+            let strict = keys.clone().object_with(values.clone(), LengthPolicy::Error, duplicates);
+            let rejects_duplicates = duplicates == DuplicatePolicy::Error && has_duplicates;
+            prop_assert_eq!(keys.len() != values.len() || rejects_duplicates, strict.is_err());
+        }
+
+        let object = keys.clone().object(values.clone());
+        prop_assert_eq!(keys.len() == values.len() && ! has_duplicates, object.is_ok());
+        if let Ok(object) = object {
+            prop_assert_eq!(keys.len(), object.len());
+            prop_assert_eq!(Ok(object.into_iter().collect::<HashMap<_, _>>()), keys.object_hashmap(values));
+        }
+    }
+
+    #[test]
+    fn test_vec_grouping(sample in values()) {
+        let mut grouped: BTreeMap<bool, Vec<i8>> = BTreeMap::new();
+        let mut counted: BTreeMap<bool, usize> = BTreeMap::new();
+        let mut indexed: BTreeMap<i8, i8> = BTreeMap::new();
+        for element in sample.iter() {
+            grouped.entry(*element % 2 == 0).or_default().push(*element);
+            *counted.entry(*element % 2 == 0).or_default() += 1;
+            indexed.insert(*element / 3, *element);
+        }
+
+        prop_assert_eq!(&grouped, &sample.clone().group_by(|v| v % 2 == 0));
+        prop_assert_eq!(grouped.into_iter().collect::<HashMap<_, _>>(), sample.clone().group_by_hashmap(|v| v % 2 == 0));
+        prop_assert_eq!(&counted, &sample.clone().count_by(|v| v % 2 == 0));
+        prop_assert_eq!(counted.into_iter().collect::<HashMap<_, _>>(), sample.clone().count_by_hashmap(|v| v % 2 == 0));
+        prop_assert_eq!(&indexed, &sample.clone().index_by(|v| v / 3));
+        prop_assert_eq!(indexed.into_iter().collect::<HashMap<_, _>>(), sample.clone().index_by_hashmap(|v| v / 3));
+        prop_assert_eq!(model_filter(&sample, |v| *v >= 0), sample.reject(|v| *v < 0));
+    }
+
+    #[test]
+    fn test_vec_slicing(sample in values(), n in 0usize..50) {
+        let chunks = sample.clone().chunk(n);
+        if n == 0 {
+            prop_assert!(chunks.is_empty());
+        } else {
+            prop_assert!(chunks.iter().all(|chunk| ! chunk.is_empty() && chunk.len() <= n));
+            prop_assert!(chunks.iter().rev().skip(1).all(|chunk| chunk.len() == n));
+            prop_assert_eq!(&sample, &chunks.clone().flatten());
+            prop_assert_eq!(&sample, &chunks.flatten_shallow());
+        }
+
+        let initial: Vec<i8> = sample.iter().take(sample.len().saturating_sub(n)).cloned().collect();
+        let rest: Vec<i8> = sample.iter().skip(n).cloned().collect();
+        prop_assert_eq!(&initial[..], sample.initial(n));
+        prop_assert_eq!(&rest[..], sample.rest(n));
+    }
+
+    #[test]
+    fn test_vec_zip_and_compact(a in values(), b in values(), options in vec(proptest::option::of(-8i8..8), 0..40)) {
+        let zipped = a.clone().zip(vec!(b.clone()));
+        let model: Vec<Vec<i8>> = a.iter().zip(b.iter()).map(|(x, y)| vec!(*x, *y)).collect();
+        prop_assert_eq!(&model, &zipped);
+
+        let length = a.len().min(b.len());
+        let unzipped = zipped.unzip();
+        if length > 0 {
+            prop_assert_eq!(vec!(a.initial(a.len() - length).to_vec(), b.initial(b.len() - length).to_vec()), unzipped);
+        } else {
+            prop_assert!(unzipped.is_empty());
+        }
+
+        let compacted: Vec<i8> = options.iter().filter_map(|v| *v).collect();
+        prop_assert_eq!(compacted, options.compact());
+    }
+
+    #[test]
+    fn test_hashmap_laws(sample in hash_map(-8i8..8, -8i8..8, 0..16), keys in values(), other in hash_map(-8i8..8, -8i8..8, 0..16)) {
+        let sample: HashMap<i8, i8> = sample;
+        let picked = sample.clone().pick(keys.clone());
+        let omitted = sample.clone().omit(keys.clone());
+        prop_assert!(picked.keys().all(|key| keys.contains(key)));
+        prop_assert!(omitted.keys().all(|key| ! keys.contains(key)));
+        let mut united = picked.clone();
+        united.extend(omitted.clone());
+        prop_assert_eq!(&sample, &united);
+
+        prop_assert_eq!(sample.clone().pick_by_filter(|&k, _| k > 0), sample.iter().filter(|&(&k, _)| k > 0).map(|(&k, &v)| (k, v)).collect::<HashMap<_, _>>());
+        prop_assert_eq!(sample.clone().omit_by_filter(|&k, _| k > 0), sample.iter().filter(|&(&k, _)| k <= 0).map(|(&k, &v)| (k, v)).collect::<HashMap<_, _>>());
+
+        let mut assigned = sample.clone();
+        assigned.extend(other.clone());
+        prop_assert_eq!(&assigned, &sample.clone().assign(other.clone()));
+        let mut defaulted = other.clone();
+        defaulted.extend(sample.clone());
+        prop_assert_eq!(&defaulted, &sample.clone().defaults(other.clone()));
+        prop_assert_eq!(&defaulted, &sample.clone().defaults_many(vec!(other.clone())));
+        prop_assert_eq!(&assigned, &sample.clone().merge_with(other.clone(), |_, _, v| v));
+        prop_assert_eq!(&assigned, &sample.clone().deep_merge(other.clone()));
+
+        let is_bijective = sample.values().collect::<HashSet<_>>().len() == sample.len();
+        prop_assert_eq!(is_bijective, sample.clone().try_invert().is_ok());
+        if is_bijective {
+            prop_assert_eq!(&sample, &sample.clone().invert().invert());
+        }
+        let multi = sample.clone().invert_multi();
+        prop_assert_eq!(sample.len(), multi.values().map(|keys| keys.len()).sum::<usize>());
+        prop_assert!(multi.iter().all(|(value, keys)| keys.iter().all(|key| sample[key] == *value)));
+
+        prop_assert_eq!(sample.values().max(), sample.max_by_value().map(|(_, value)| value));
+        prop_assert_eq!(sample.values().min(), sample.min_by_value().map(|(_, value)| value));
+        let mut sorted: Vec<i8> = sample.values().cloned().collect();
+        sorted.sort();
+        prop_assert_eq!(&sorted, &sample.clone().sort_by_value().into_iter().map(|(_, value)| value).collect::<Vec<_>>());
+        let (passed, failed) = sample.clone().partition(|&k, _| k > 0);
+        prop_assert_eq!(&passed, &sample.clone().pick_by_filter(|&k, _| k > 0));
+        prop_assert_eq!(&failed, &sample.clone().omit_by_filter(|&k, _| k > 0));
+        prop_assert_eq!(sample.clone().map_values(|_, v| v as i16 * 2), sample.iter().map(|(&k, &v)| (k, v as i16 * 2)).collect::<HashMap<_, _>>());
+        prop_assert_eq!(sample.clone().filter_map_values(|_, v| if v > 0 { Some(v) } else { None }), sample.clone().pick_by_filter(|_, &v| v > 0));
+        prop_assert_eq!(Ok(sample.clone()), sample.clone().map_keys(|&k, _| k));
+        prop_assert_eq!(sample.values().map(|&v| v as i64).sum::<i64>(), sample.clone().map_values(|_, v| v as i64).map_keys_with(|_, &v| v, |_, a, b| a + b).values().sum::<i64>());
+        prop_assert_eq!(sample.clone().invert_multi().len(), sample.clone().map_keys_with(|_, &v| v, |_, a, _| a).len());
+        prop_assert_eq!(sample.len() == sample.clone().invert_multi().len(), sample.clone().map_keys(|_, &v| v).is_ok());
+        prop_assert_eq!(sample.values().any(|&v| v == 0), sample.find_key(|_, &v| v == 0).is_some());
+
+        let top: Vec<i8> = sorted.iter().rev().take(3).cloned().collect();
+        prop_assert_eq!(top, sample.top_n(3, |_, &value| value).into_iter().map(|(_, value)| value).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_treemap_laws(sample in btree_map(-8i8..8, -8i8..8, 0..16), keys in values(), other in btree_map(-8i8..8, -8i8..8, 0..16)) {
+        let sample: BTreeMap<i8, i8> = sample;
+        let picked = sample.clone().pick(&keys);
+        let omitted = sample.clone().omit(&keys);
+        prop_assert!(picked.keys().all(|key| keys.contains(key)));
+        prop_assert!(omitted.keys().all(|key| ! keys.contains(key)));
+        let mut united = picked.clone();
+        united.extend(omitted.clone());
+        prop_assert_eq!(&sample, &united);
+
+        prop_assert_eq!(sample.clone().pick_by_filter(|&k, _| k > 0), sample.iter().filter(|&(&k, _)| k > 0).map(|(&k, &v)| (k, v)).collect::<BTreeMap<_, _>>());
+        prop_assert_eq!(sample.clone().omit_by_filter(|&k, _| k > 0), sample.iter().filter(|&(&k, _)| k <= 0).map(|(&k, &v)| (k, v)).collect::<BTreeMap<_, _>>());
+        prop_assert_eq!(sample.pairs(), sample.iter().collect::<Vec<_>>());
+
+        let mut assigned = sample.clone();
+        assigned.extend(other.clone());
+        prop_assert_eq!(&assigned, &sample.clone().assign(other.clone()));
+        let mut defaulted = other.clone();
+        defaulted.extend(sample.clone());
+        prop_assert_eq!(&defaulted, &sample.clone().defaults(other.clone()));
+        prop_assert_eq!(&defaulted, &sample.clone().defaults_many(vec!(other.clone())));
+        prop_assert_eq!(&assigned, &sample.clone().merge_with(other.clone(), |_, _, v| v));
+        prop_assert_eq!(&assigned, &sample.clone().deep_merge(other.clone()));
+
+        let is_bijective = sample.values().collect::<HashSet<_>>().len() == sample.len();
+        prop_assert_eq!(is_bijective, sample.clone().try_invert().is_ok());
+        if is_bijective {
+            prop_assert_eq!(&sample, &sample.clone().invert().invert());
+        }
+        let multi = sample.clone().invert_multi();
+        prop_assert_eq!(sample.len(), multi.values().map(|keys| keys.len()).sum::<usize>());
+        prop_assert!(multi.iter().all(|(value, keys)| keys.iter().all(|key| sample[key] == *value)));
+
+        // Ties are broken by key order, so the model is a stable sort of the entries.
+        let mut sorted: Vec<(i8, i8)> = sample.clone().into_iter().collect();
+        sorted.sort_by_key(|&(_, value)| value);
+        prop_assert_eq!(&sorted, &sample.clone().sort_by_value());
+        prop_assert_eq!(sorted.first().map(|(key, value)| (key, value)), sample.min_by_value());
+        let mut descending = sorted.clone();
+        descending.sort_by_key(|&(_, value)| std::cmp::Reverse(value));
+        prop_assert_eq!(descending.first().map(|(key, value)| (key, value)), sample.max_by_value());
+        prop_assert_eq!(descending.first().map(|(key, value)| (key, value)), sample.max_entry_by(|_, &value| value));
+        descending.truncate(3);
+        prop_assert_eq!(descending, sample.clone().top_n(3, |_, &value| value));
+
+        let (passed, failed) = sample.clone().partition(|&k, _| k > 0);
+        prop_assert_eq!(&passed, &sample.clone().pick_by_filter(|&k, _| k > 0));
+        prop_assert_eq!(&failed, &sample.clone().omit_by_filter(|&k, _| k > 0));
+        prop_assert_eq!(sample.iter().find(|&(_, &v)| v == 0).map(|(k, _)| k), sample.find_key(|_, &v| v == 0));
+        prop_assert_eq!(Ok(sample.clone()), sample.clone().map_keys(|&k, _| k));
+        prop_assert_eq!(sample.len() == sample.clone().invert_multi().len(), sample.clone().map_keys(|_, &v| v).is_ok());
+
+        // resolve gets the values in key order, so keeping the first or the last one matches invert_multi.
+        let multi = sample.clone().invert_multi();
+        let first: BTreeMap<i8, i8> = multi.iter().filter_map(|(&v, keys)| keys.first().map(|&k| (v, k))).collect();
+        let last: BTreeMap<i8, i8> = multi.iter().filter_map(|(&v, keys)| keys.last().map(|&k| (v, k))).collect();
+        let entries: BTreeMap<i8, (i8, i8)> = sample.iter().map(|(&k, &v)| (k, (k, v))).collect();
+        prop_assert_eq!(first, entries.clone().map_keys_with(|_, &(_, v)| v, |_, first, _| first).map_values(|_, (k, _)| k));
+        prop_assert_eq!(last, entries.map_keys_with(|_, &(_, v)| v, |_, _, last| last).map_values(|_, (k, _)| k));
+    }
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "rand")]
+
+extern crate rand;
+extern crate underscore;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use underscore::vec::VecU;
+use underscore::random::random;
+
+#[test]
+fn test_rand_rng_as_random_source() {
+    let sample: Vec<isize> = (0..20).collect();
+    let shuffled = sample.clone().shuffle_with(&mut StdRng::seed_from_u64(1));
+    assert_eq!(shuffled, sample.clone().shuffle_with(&mut StdRng::seed_from_u64(1)));
+
+    let mut sorted = shuffled;
+    sorted.sort();
+    assert_eq!(sample, sorted);
+
+    let mut rng = rand::rng();
+    assert_eq!(3usize, sample.clone().sample(3, &mut rng).len());
+    assert!((1..=6).contains(&random(1, 6, &mut rng)));
+}